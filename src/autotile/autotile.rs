@@ -0,0 +1,250 @@
+use crate::{
+    direction::{Direction, DirectionFlags},
+    grid::Grid,
+};
+
+/// The bits of a neighbour mask, the cardinal bits match [`Direction`]'s.
+pub struct AutotileFlags;
+
+#[rustfmt::skip]
+impl AutotileFlags {
+    pub const NORTH:      u8 = DirectionFlags::NORTH;
+    pub const EAST:       u8 = DirectionFlags::EAST;
+    pub const SOUTH:      u8 = DirectionFlags::SOUTH;
+    pub const WEST:       u8 = DirectionFlags::WEST;
+    pub const NORTH_EAST: u8 = 1 << 4;
+    pub const SOUTH_EAST: u8 = 1 << 5;
+    pub const SOUTH_WEST: u8 = 1 << 6;
+    pub const NORTH_WEST: u8 = 1 << 7;
+}
+
+#[rustfmt::skip]
+const NEIGHBOR_FLAGS: [(Direction, u8); 8] = [
+    (Direction::NORTH,      AutotileFlags::NORTH),
+    (Direction::EAST,       AutotileFlags::EAST),
+    (Direction::SOUTH,      AutotileFlags::SOUTH),
+    (Direction::WEST,       AutotileFlags::WEST),
+    (Direction::NORTH_EAST, AutotileFlags::NORTH_EAST),
+    (Direction::SOUTH_EAST, AutotileFlags::SOUTH_EAST),
+    (Direction::SOUTH_WEST, AutotileFlags::SOUTH_WEST),
+    (Direction::NORTH_WEST, AutotileFlags::NORTH_WEST),
+];
+
+/// The reduced blob masks, ascending, a tile's index is its position in this table.
+pub const BLOB_TILE_MASKS: [u8; 47] = blob_tables().0;
+
+/// The tile index of every blob mask.
+pub const BLOB_TILE_INDEX: [u8; 256] = blob_tables().1;
+
+/// How neighbours are turned into a tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Autotile {
+    /// 16 tiles, from the 4 cardinal neighbours.
+    Cardinal,
+    /// 47 tiles, from all 8 neighbours. A corner only counts when both sides next to it do.
+    Blob,
+}
+
+impl Autotile {
+    /// Get the number of distinct tiles.
+    #[inline]
+    pub const fn tile_count(self) -> usize {
+        match self {
+            Self::Cardinal => 16,
+            Self::Blob => BLOB_TILE_MASKS.len(),
+        }
+    }
+
+    /// Get the tile index of a mask.
+    #[inline]
+    pub const fn tile_index(self, mask: u8) -> u8 {
+        match self {
+            Self::Cardinal => mask & 0x0F,
+            Self::Blob => BLOB_TILE_INDEX[mask as usize],
+        }
+    }
+
+    /// Get the neighbour mask of `position`.
+    ///
+    /// `is_same` is called with the value at `position` and a neighbour's value, neighbours
+    /// outside the grid never match.
+    pub fn mask<T>(
+        self,
+        grid: &Grid<T>,
+        position: (i32, i32),
+        mut is_same: impl FnMut(&T, &T) -> bool,
+    ) -> u8 {
+        let Some(value) = grid.get(position) else {
+            return 0;
+        };
+        let neighbors = match self {
+            Self::Cardinal => &NEIGHBOR_FLAGS[..4],
+            Self::Blob => &NEIGHBOR_FLAGS[..],
+        };
+
+        let mut mask = 0;
+        for &(direction, flag) in neighbors {
+            let (x, y) = direction.coord();
+            if grid
+                .get((position.0 + x, position.1 + y))
+                .is_some_and(|neighbor| is_same(value, neighbor))
+            {
+                mask |= flag;
+            }
+        }
+
+        match self {
+            Self::Cardinal => mask,
+            Self::Blob => reduce_blob(mask),
+        }
+    }
+
+    /// Get the neighbour mask of every position of `grid`.
+    pub fn masks<T>(self, grid: &Grid<T>, mut is_same: impl FnMut(&T, &T) -> bool) -> Grid<u8> {
+        Grid::new_fn(grid.size(), |_, position| {
+            self.mask(grid, position, &mut is_same)
+        })
+    }
+
+    /// Recomputes the masks of a changed `position` and its neighbours.
+    pub fn update<T>(
+        self,
+        masks: &mut Grid<u8>,
+        grid: &Grid<T>,
+        position: (i32, i32),
+        mut is_same: impl FnMut(&T, &T) -> bool,
+    ) {
+        let neighbors = match self {
+            Self::Cardinal => &NEIGHBOR_FLAGS[..4],
+            Self::Blob => &NEIGHBOR_FLAGS[..],
+        };
+        let positions = std::iter::once(position).chain(neighbors.iter().map(|(direction, _)| {
+            let (x, y) = direction.coord();
+            (position.0 + x, position.1 + y)
+        }));
+
+        for position in positions {
+            if let Some(mask) = masks.get_mut(position) {
+                *mask = self.mask(grid, position, &mut is_same);
+            }
+        }
+    }
+}
+
+/// Drops the corners of a mask which are not next to both of their sides.
+const fn reduce_blob(mask: u8) -> u8 {
+    let mut reduced = mask & 0x0F;
+    let corners = [
+        (
+            AutotileFlags::NORTH_EAST,
+            AutotileFlags::NORTH | AutotileFlags::EAST,
+        ),
+        (
+            AutotileFlags::SOUTH_EAST,
+            AutotileFlags::SOUTH | AutotileFlags::EAST,
+        ),
+        (
+            AutotileFlags::SOUTH_WEST,
+            AutotileFlags::SOUTH | AutotileFlags::WEST,
+        ),
+        (
+            AutotileFlags::NORTH_WEST,
+            AutotileFlags::NORTH | AutotileFlags::WEST,
+        ),
+    ];
+
+    let mut i = 0;
+    while i < corners.len() {
+        let (corner, sides) = corners[i];
+        if mask & corner != 0 && mask & sides == sides {
+            reduced |= corner;
+        }
+        i += 1;
+    }
+    reduced
+}
+
+/// Builds [`BLOB_TILE_MASKS`] and [`BLOB_TILE_INDEX`].
+const fn blob_tables() -> ([u8; 47], [u8; 256]) {
+    let mut masks = [0; 47];
+    let mut count = 0;
+    let mut mask = 0;
+    while mask < 256 {
+        // a reduced mask reduces to itself
+        if reduce_blob(mask as u8) == mask as u8 {
+            masks[count] = mask as u8;
+            count += 1;
+        }
+        mask += 1;
+    }
+
+    let mut index = [0; 256];
+    let mut mask = 0;
+    while mask < 256 {
+        let reduced = reduce_blob(mask as u8);
+        let mut tile = 0;
+        while masks[tile] != reduced {
+            tile += 1;
+        }
+        index[mask] = tile as u8;
+        mask += 1;
+    }
+    (masks, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Autotile, AutotileFlags, BLOB_TILE_INDEX, BLOB_TILE_MASKS};
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_blob_tables() {
+        for (tile, &mask) in BLOB_TILE_MASKS.iter().enumerate() {
+            assert_eq!(BLOB_TILE_INDEX[mask as usize] as usize, tile);
+        }
+        assert!(BLOB_TILE_INDEX.iter().all(|&tile| (tile as usize) < 47));
+
+        // a lone corner is dropped
+        assert_eq!(
+            Autotile::Blob.tile_index(AutotileFlags::NORTH_EAST),
+            Autotile::Blob.tile_index(0)
+        );
+        assert_ne!(
+            Autotile::Blob
+                .tile_index(AutotileFlags::NORTH | AutotileFlags::EAST | AutotileFlags::NORTH_EAST),
+            Autotile::Blob.tile_index(AutotileFlags::NORTH | AutotileFlags::EAST)
+        );
+    }
+
+    #[test]
+    fn test_autotile_masks() {
+        // a 3x3 block of walls in the corner of a 5x5 grid
+        let mut grid = Grid::new_fn((5, 5), |_, (x, y)| x < 3 && y < 3);
+        let same = |a: &bool, b: &bool| a == b;
+
+        let masks = Autotile::Cardinal.masks(&grid, same);
+        assert_eq!(masks[(1i32, 1)], 0x0F);
+        assert_eq!(masks[(0i32, 0)], AutotileFlags::NORTH | AutotileFlags::EAST);
+        assert_eq!(
+            masks[(2i32, 1)],
+            AutotileFlags::NORTH | AutotileFlags::SOUTH | AutotileFlags::WEST
+        );
+
+        let mut blob = Autotile::Blob.masks(&grid, same);
+        assert_eq!(blob[(1i32, 1)], 0xFF);
+        assert_eq!(
+            blob[(0i32, 0)],
+            AutotileFlags::NORTH | AutotileFlags::EAST | AutotileFlags::NORTH_EAST
+        );
+
+        // knock a hole in the block
+        grid[(1i32, 1)] = false;
+        Autotile::Blob.update(&mut blob, &grid, (1, 1), same);
+        assert!(blob.iter().eq(Autotile::Blob.masks(&grid, same).iter()));
+        let mut cardinal = masks;
+        Autotile::Cardinal.update(&mut cardinal, &grid, (1, 1), same);
+        assert!(cardinal
+            .iter()
+            .eq(Autotile::Cardinal.masks(&grid, same).iter()));
+    }
+}
@@ -0,0 +1,2 @@
+mod autotile;
+pub use self::autotile::*;
@@ -3,9 +3,16 @@ use std::path::Path;
 use bevy::prelude::*;
 
 use crate::{
-    direction::Direction,
+    direction::{Direction, DirectionSet, DirectionTransform, Facing, RelativeDirection, YAxis},
+    distance::Distance,
+    generic_asset_loader::GenericAssetLoader,
     grid::Grid,
-    grid_shapes::{Circle, Line, Rectangle},
+    grid_shapes::{
+        Arc, Circle, Cone, Cross, CubicBezier, Cuboid, Cylinder, DdaLine, Diamond, DistanceRange,
+        DistanceRing, Ellipse, Line, Line3, Polygon, QuadraticBezier, Rectangle, Ring, ShapeDef,
+        Sphere, Spiral, SupercoverLine, SymmetricLine, ThickLine, Triangle,
+    },
+    hex::{Hex, HexDirection, HexLayout, HexLine, HexOrientation, HexRange, HexRing, HexSpiral},
     random::{Dice, Random},
     resources::Folders,
     systems::remove_resource,
@@ -52,6 +59,14 @@ impl Plugin for BrtPlugin {
     fn build(&self, app: &mut App) {
         // Direction
         app.register_type::<Direction>();
+        app.register_type::<DirectionSet>();
+        app.register_type::<DirectionTransform>();
+        app.register_type::<RelativeDirection>();
+        app.register_type::<Facing>();
+        app.register_type::<YAxis>();
+
+        // Distance
+        app.register_type::<Distance>();
 
         // Grid
         app.register_type::<Grid<Entity>>();
@@ -60,7 +75,39 @@ impl Plugin for BrtPlugin {
         app.register_type::<Grid<bool>>();
         app.register_type::<Circle>();
         app.register_type::<Line>();
+        app.register_type::<DdaLine>();
+        app.register_type::<SupercoverLine>();
+        app.register_type::<SymmetricLine>();
+        app.register_type::<ThickLine>();
+        app.register_type::<DistanceRange>();
+        app.register_type::<DistanceRing>();
+        app.register_type::<Arc>();
+        app.register_type::<CubicBezier>();
+        app.register_type::<QuadraticBezier>();
+        app.register_type::<Spiral>();
+        app.register_type::<Cuboid>();
+        app.register_type::<Cylinder>();
+        app.register_type::<Line3>();
+        app.register_type::<Sphere>();
         app.register_type::<Rectangle>();
+        app.register_type::<Cone>();
+        app.register_type::<Cross>();
+        app.register_type::<Diamond>();
+        app.register_type::<Ellipse>();
+        app.register_type::<Polygon>();
+        app.register_type::<Ring>();
+        app.register_type::<Triangle>();
+        app.register_type::<ShapeDef>();
+
+        // Hex
+        app.register_type::<Hex>();
+        app.register_type::<HexDirection>();
+        app.register_type::<HexLayout>();
+        app.register_type::<HexOrientation>();
+        app.register_type::<HexLine>();
+        app.register_type::<HexRange>();
+        app.register_type::<HexRing>();
+        app.register_type::<HexSpiral>();
 
         app.register_type::<Folders>();
         app.insert_resource(self.folders.clone());
@@ -77,4 +124,60 @@ impl Plugin for BrtPlugin {
             );
         }
     }
+
+    fn finish(&self, app: &mut App) {
+        // Only load `ShapeDef`s when the app loads assets, checked once every plugin is built so
+        // `AssetPlugin` may be added after this plugin
+        if app.world().contains_resource::<AssetServer>() {
+            app.init_asset::<ShapeDef>();
+            app.register_asset_loader(GenericAssetLoader::<ShapeDef>::new(ShapeDef::EXTENSIONS));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{asset::AssetPlugin, prelude::*, tasks::block_on};
+
+    use super::BrtPlugin;
+    use crate::grid_shapes::ShapeDef;
+
+    fn brt_plugin() -> BrtPlugin {
+        BrtPlugin::new("", "com", "brt", "brt-test")
+    }
+
+    fn assert_loads_shape_defs(mut app: App) {
+        app.finish();
+        app.cleanup();
+        assert!(app.world().contains_resource::<Assets<ShapeDef>>());
+        let asset_server = app.world().resource::<AssetServer>();
+        for extension in ShapeDef::EXTENSIONS {
+            assert!(block_on(asset_server.get_asset_loader_with_extension(extension)).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_brt_plugin_shape_def_loader() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            brt_plugin(),
+        ));
+        assert_loads_shape_defs(app);
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            brt_plugin(),
+            AssetPlugin::default(),
+        ));
+        assert_loads_shape_defs(app);
+
+        // without assets there is nothing to load
+        let mut app = App::new();
+        app.add_plugins(brt_plugin());
+        app.finish();
+        assert!(!app.world().contains_resource::<Assets<ShapeDef>>());
+    }
 }
@@ -4,12 +4,15 @@ use std::{
         Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub,
         SubAssign,
     },
+    str::FromStr,
 };
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::direction::{DirectionFlags, DirectionIter};
+use crate::direction::{
+    DirectionError, DirectionFlags, DirectionIter, YAxis, DIRECTION_INDEX, DIRECTION_NAMES,
+};
 
 /// Includes Directions [`NORTH`, `EAST`, `SOUTH`, `WEST`]
 pub struct CardinalDirection;
@@ -131,6 +134,41 @@ impl Direction {
     pub const WEST: Self = Self(DirectionFlags::WEST);
 }
 
+// Bits
+impl Direction {
+    /// Get the raw [`DirectionFlags`] of the [`Direction`]
+    #[inline]
+    pub(crate) const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Creates a [`Direction`] from raw [`DirectionFlags`], unused bits are dropped
+    #[inline]
+    pub(crate) const fn from_bits(bits: u8) -> Self {
+        Self(bits & 0b11_1111)
+    }
+}
+
+// Names
+impl Direction {
+    /// Get the lower snake case name of the [`Direction`], e.g. `"up_north_west"`
+    ///
+    /// Returns `"none"` for `NONE` and `None` for combinations such as `NORTH + SOUTH` or
+    /// unknown bits.
+    pub const fn name(self) -> Option<&'static str> {
+        if self.0 == 0 {
+            return Some("none");
+        }
+        if self.0 as usize >= DIRECTION_INDEX.len() {
+            return None;
+        }
+        match DIRECTION_INDEX[self.0 as usize] {
+            u8::MAX => None,
+            index => Some(DIRECTION_NAMES[index as usize]),
+        }
+    }
+}
+
 // To-From Coord
 impl Direction {
     /// Retrieves the 2d coordinate value from the [`Direction`] where:
@@ -243,6 +281,254 @@ impl Direction {
     }
 }
 
+// Y axis
+impl Direction {
+    /// Retrieves the 2d coordinate value from the [`Direction`] with `NORTH` pointing along
+    /// `y_axis`, see [`coord`](Self::coord)
+    #[inline]
+    pub const fn coord_in(self, y_axis: YAxis) -> (i32, i32) {
+        y_axis.convert(self.coord())
+    }
+
+    /// Retrieves the 3d coordinate value from the [`Direction`] with `NORTH` pointing along
+    /// `y_axis`, see [`coord3d`](Self::coord3d)
+    #[inline]
+    pub const fn coord3d_in(self, y_axis: YAxis) -> (i32, i32, i32) {
+        let (x, y, z) = self.coord3d();
+        (x, y * y_axis.north_sign(), z)
+    }
+
+    /// Retrieves the [`Direction`] from a 2d coordinate value with `NORTH` pointing along
+    /// `y_axis`, see [`from_coord`](Self::from_coord)
+    #[inline]
+    pub fn from_coord_in(coord: (i32, i32), y_axis: YAxis) -> Self {
+        Self::from_coord(y_axis.convert(coord))
+    }
+
+    /// Retrieves the [`Direction`] from a 3d coordinate value with `NORTH` pointing along
+    /// `y_axis`, see [`from_coord3d`](Self::from_coord3d)
+    #[inline]
+    pub fn from_coord3d_in(coord: (i32, i32, i32), y_axis: YAxis) -> Self {
+        Self::from_coord3d((coord.0, coord.1 * y_axis.north_sign(), coord.2))
+    }
+
+    /// Swaps `NORTH` and `SOUTH`, mirroring the [`Direction`] across the `X` axis
+    pub fn flip_y(self) -> Self {
+        let (x, y, z) = self.coord3d();
+        Self::from_coord3d((x, -y, z))
+    }
+}
+
+// Bevy math
+impl Direction {
+    /// Get the [`coord`](Self::coord) as an [`IVec2`]
+    #[inline]
+    pub const fn as_ivec2(self) -> IVec2 {
+        let (x, y) = self.coord();
+        IVec2::new(x, y)
+    }
+
+    /// Get the [`coord3d`](Self::coord3d) as an [`IVec3`]
+    #[inline]
+    pub const fn as_ivec3(self) -> IVec3 {
+        let (x, y, z) = self.coord3d();
+        IVec3::new(x, y, z)
+    }
+
+    /// Get the unit [`Vec2`] pointing in the [`Direction`], [`Vec2::ZERO`] without a horizontal
+    /// component
+    #[inline]
+    pub fn as_vec2(self) -> Vec2 {
+        self.as_ivec2().as_vec2().normalize_or_zero()
+    }
+
+    /// Get the unit [`Vec3`] pointing in the [`Direction`], [`Vec3::ZERO`] for `NONE`
+    #[inline]
+    pub fn as_vec3(self) -> Vec3 {
+        self.as_ivec3().as_vec3().normalize_or_zero()
+    }
+
+    /// Get the [`Dir2`] pointing in the [`Direction`], `None` without a horizontal component
+    #[inline]
+    pub fn as_dir2(self) -> Option<Dir2> {
+        Dir2::new(self.as_ivec2().as_vec2()).ok()
+    }
+
+    /// Get the [`Dir3`] pointing in the [`Direction`], `None` for `NONE`
+    #[inline]
+    pub fn as_dir3(self) -> Option<Dir3> {
+        Dir3::new(self.as_ivec3().as_vec3()).ok()
+    }
+
+    /// Get the angle in radians, counter clockwise from `EAST`
+    ///
+    /// NOTE: A [`Direction`] without a horizontal component has an angle of `0`.
+    #[inline]
+    pub fn angle(self) -> f32 {
+        let (x, y) = self.coord();
+        (y as f32).atan2(x as f32)
+    }
+
+    /// Get the angle in degrees, counter clockwise from `EAST`
+    ///
+    /// NOTE: A [`Direction`] without a horizontal component has an angle of `0`.
+    #[inline]
+    pub fn angle_degrees(self) -> f32 {
+        self.angle().to_degrees()
+    }
+
+    /// Snaps an angle in radians, counter clockwise from `EAST`, to the closest of the 8
+    /// cardinal and ordinal [`Direction`]s, `NONE` if the angle is not finite
+    pub fn from_angle(angle: f32) -> Self {
+        const SECTORS: [Direction; 8] = [
+            Direction::EAST,
+            Direction::NORTH_EAST,
+            Direction::NORTH,
+            Direction::NORTH_WEST,
+            Direction::WEST,
+            Direction::SOUTH_WEST,
+            Direction::SOUTH,
+            Direction::SOUTH_EAST,
+        ];
+        Self::snap_angle(angle, &SECTORS)
+    }
+
+    /// Snaps an angle in radians, counter clockwise from `EAST`, to the closest of the 4
+    /// cardinal [`Direction`]s, `NONE` if the angle is not finite
+    pub fn from_angle_cardinal(angle: f32) -> Self {
+        const SECTORS: [Direction; 4] = [
+            Direction::EAST,
+            Direction::NORTH,
+            Direction::WEST,
+            Direction::SOUTH,
+        ];
+        Self::snap_angle(angle, &SECTORS)
+    }
+
+    /// Snaps a vector to the closest of the 8 cardinal and ordinal [`Direction`]s, `NONE` for
+    /// a zero or non finite vector
+    pub fn nearest_from_vec2(vec: Vec2) -> Self {
+        if vec == Vec2::ZERO || !vec.is_finite() {
+            return Self::NONE;
+        }
+        Self::from_angle(vec.to_angle())
+    }
+
+    /// Snaps a vector to the closest of the 4 cardinal [`Direction`]s, `NONE` for a zero or non
+    /// finite vector
+    pub fn nearest_cardinal_from_vec2(vec: Vec2) -> Self {
+        if vec == Vec2::ZERO || !vec.is_finite() {
+            return Self::NONE;
+        }
+        Self::from_angle_cardinal(vec.to_angle())
+    }
+
+    /// Picks the sector of `angle` where `sectors` split the circle evenly, starting at `EAST`
+    fn snap_angle(angle: f32, sectors: &[Self]) -> Self {
+        if !angle.is_finite() {
+            return Self::NONE;
+        }
+        let count = sectors.len() as f32;
+        let sector = (angle / std::f32::consts::TAU * count)
+            .round()
+            .rem_euclid(count);
+        // rounding can land on `count` itself
+        sectors[sector as usize % sectors.len()]
+    }
+}
+
+// Toward
+impl Direction {
+    /// Get the 8 way [`Direction`] from `from` toward `to` by the sign of each axis, `NONE` when
+    /// they are the same position
+    #[inline]
+    pub fn toward(from: (i32, i32), to: (i32, i32)) -> Self {
+        Self::from_coord((to.0 - from.0, to.1 - from.1))
+    }
+
+    /// Get the 3d [`Direction`] from `from` toward `to` by the sign of each axis, `NONE` when
+    /// they are the same position
+    #[inline]
+    pub fn toward3d(from: (i32, i32, i32), to: (i32, i32, i32)) -> Self {
+        Self::from_coord3d((to.0 - from.0, to.1 - from.1, to.2 - from.2))
+    }
+
+    /// Get the cardinal [`Direction`] from `from` toward `to` along the axis with the larger
+    /// distance, `NONE` when they are the same position
+    ///
+    /// NOTE: Ties step along the `X` axis.
+    pub fn toward_cardinal(from: (i32, i32), to: (i32, i32)) -> Self {
+        let (x, y) = (to.0 - from.0, to.1 - from.1);
+        if x.abs() >= y.abs() {
+            Self::from_coord((x, 0))
+        } else {
+            Self::from_coord((0, y))
+        }
+    }
+
+    /// Get the cardinal or ordinal [`Direction`] closest to the angle from `from` to `to`,
+    /// `NONE` when they are the same position
+    ///
+    /// Unlike [`toward`](Self::toward) a mostly horizontal delta such as `(5, 1)` is `EAST`.
+    #[inline]
+    pub fn approximate_between(from: (i32, i32), to: (i32, i32)) -> Self {
+        Self::nearest_from_vec2(Vec2::new((to.0 - from.0) as f32, (to.1 - from.1) as f32))
+    }
+
+    /// Get every cardinal and ordinal [`Direction`] from `from` toward `to`, best first
+    ///
+    /// Starts with [`approximate_between`](Self::approximate_between) and fans out 45 degrees
+    /// at a time, the side closer to `to` first, ending with the opposite direction. Yields
+    /// nothing when they are the same position.
+    pub fn preferred_toward(from: (i32, i32), to: (i32, i32)) -> impl Iterator<Item = Self> {
+        let best = Self::approximate_between(from, to);
+        let delta = Vec2::new((to.0 - from.0) as f32, (to.1 - from.1) as f32);
+        let left_first = best.as_vec2().perp_dot(delta) >= 0.0;
+
+        let mut near = [best.left45(), best.left90(), best.left135()];
+        let mut far = [best.right45(), best.right90(), best.right135()];
+        if !left_first {
+            std::mem::swap(&mut near, &mut far);
+        }
+
+        let count = if best == Self::NONE { 0 } else { 8 };
+        [
+            best,
+            near[0],
+            far[0],
+            near[1],
+            far[1],
+            near[2],
+            far[2],
+            best.opposite(),
+        ]
+        .into_iter()
+        .take(count)
+    }
+
+    /// Get the position one step from `position` in the [`Direction`]
+    #[inline]
+    pub const fn step(self, position: (i32, i32)) -> (i32, i32) {
+        let (x, y) = self.coord();
+        (position.0 + x, position.1 + y)
+    }
+
+    /// Get the position one step from `position` in the [`Direction`] with `NORTH` pointing
+    /// along `y_axis`
+    #[inline]
+    pub const fn step_in(self, position: (i32, i32), y_axis: YAxis) -> (i32, i32) {
+        let (x, y) = self.coord_in(y_axis);
+        (position.0 + x, position.1 + y)
+    }
+
+    /// Get the position one step from `position` in the 3d [`Direction`]
+    #[inline]
+    pub const fn step3d(self, position: (i32, i32, i32)) -> (i32, i32, i32) {
+        let (x, y, z) = self.coord3d();
+        (position.0 + x, position.1 + y, position.2 + z)
+    }
+}
+
 // Rotations
 impl Direction {
     /// Rotates a [`Direction`] counter clockwise by one step:
@@ -410,6 +696,64 @@ impl Direction {
     }
 }
 
+// Rotations 3d
+impl Direction {
+    /// Rotates a [`Direction`] around the `X` (`EAST`) axis by `steps` of 45 degrees, counter
+    /// clockwise when looking from `EAST`:
+    ///
+    /// `NORTH` becomes `UP_NORTH`
+    ///
+    /// `UP` becomes `UP_SOUTH`
+    ///
+    /// etc...
+    pub fn rotate_x(self, steps: i32) -> Self {
+        let (x, y, z) = self.coord3d();
+        let (y, z) = rotate_square((y, z), steps);
+        Self::from_coord3d((x, y, z))
+    }
+
+    /// Rotates a [`Direction`] around the `Y` (`NORTH`) axis by `steps` of 45 degrees, counter
+    /// clockwise when looking from `NORTH`:
+    ///
+    /// `UP` becomes `UP_EAST`
+    ///
+    /// `EAST` becomes `DOWN_EAST`
+    ///
+    /// etc...
+    pub fn rotate_y(self, steps: i32) -> Self {
+        let (x, y, z) = self.coord3d();
+        let (z, x) = rotate_square((z, x), steps);
+        Self::from_coord3d((x, y, z))
+    }
+
+    /// Rotates a [`Direction`] around the `Z` (`UP`) axis by `steps` of 45 degrees, counter
+    /// clockwise when looking from `UP`, the same as [`left45`](Self::left45) for one step:
+    ///
+    /// `EAST` becomes `NORTH_EAST`
+    ///
+    /// `NORTH` becomes `NORTH_WEST`
+    ///
+    /// etc...
+    pub fn rotate_z(self, steps: i32) -> Self {
+        let (x, y, z) = self.coord3d();
+        let (x, y) = rotate_square((x, y), steps);
+        Self::from_coord3d((x, y, z))
+    }
+}
+
+/// Moves a coordinate around the square ring of 8 neighbours by `steps`, counter clockwise
+fn rotate_square(coord: (i32, i32), steps: i32) -> (i32, i32) {
+    #[rustfmt::skip]
+    const RING: [(i32, i32); 8] = [
+        (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1),
+    ];
+
+    match RING.iter().position(|&ring| ring == coord) {
+        Some(index) => RING[(index as i32 + steps).rem_euclid(8) as usize],
+        None => coord,
+    }
+}
+
 // Checks
 impl Direction {
     /// Checks a [`Direction`] to determine if it is marked as `NORTH`
@@ -476,14 +820,16 @@ impl Direction {
 
 // Iterators
 impl Direction {
-    /// Returns an iterator over the [`Direction`]s [`NORTH`, `NORTH_EAST`, `EAST`,
-    /// `SOUTH_EAST`, `SOUTH`, `SOUTH_WEST`, `WEST`, `NORTH_WEST`]
+    /// Returns an iterator over the [`Direction`]s [`NORTH`, `EAST`, `SOUTH`, `WEST`,
+    /// `NORTH_EAST`, `SOUTH_EAST`, `SOUTH_WEST`, `NORTH_WEST`]
+    ///
+    /// NOTE: This is not a clockwise order, see [`iter_clockwise`](Self::iter_clockwise).
     pub const fn iter_cardinal_ordinal() -> DirectionIter {
         DirectionIter::cardinal_ordinal()
     }
 
-    /// Returns an iterator over the [`Direction`]s [`NORTH`, `NORTH_EAST`, `EAST`,
-    /// `SOUTH_EAST`, `SOUTH`, `SOUTH_WEST`, `WEST`, `NORTH_WEST`, `UP`, `DOWN`]
+    /// Returns an iterator over the [`Direction`]s [`NORTH`, `EAST`, `SOUTH`, `WEST`,
+    /// `NORTH_EAST`, `SOUTH_EAST`, `SOUTH_WEST`, `NORTH_WEST`, `UP`, `DOWN`]
     pub const fn iter_cardinal_ordinal_vertical() -> DirectionIter {
         DirectionIter::cardinal_ordinal_vertical()
     }
@@ -509,6 +855,18 @@ impl Direction {
     pub const fn iter_vertical() -> DirectionIter {
         DirectionIter::vertical()
     }
+
+    /// Returns an iterator over the 8 cardinal and ordinal [`Direction`]s clockwise from
+    /// `start`, see [`DirectionIter::clockwise`]
+    pub fn iter_clockwise(start: Direction) -> DirectionIter {
+        DirectionIter::clockwise(start)
+    }
+
+    /// Returns an iterator over the 8 cardinal and ordinal [`Direction`]s counter clockwise from
+    /// `start`, see [`DirectionIter::counter_clockwise`]
+    pub fn iter_counter_clockwise(start: Direction) -> DirectionIter {
+        DirectionIter::counter_clockwise(start)
+    }
 }
 
 impl Display for Direction {
@@ -562,6 +920,120 @@ impl Display for Direction {
     }
 }
 
+/// Parses names such as `"N"`, `"north"`, `"north_east"`, `"NE"` and `"up-north-west"`
+///
+/// Parts are split on `_`, `-`, `,` or whitespace and are not case sensitive, a part is either
+/// a full name or its initials in canonical order, e.g. `"unw"` but not `"nwu"`. `"none"` and
+/// the [`Display`] form are accepted too.
+impl FromStr for Direction {
+    type Err = DirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const PARTS: [(&str, Direction); 6] = [
+            ("north", Direction::NORTH),
+            ("east", Direction::EAST),
+            ("south", Direction::SOUTH),
+            ("west", Direction::WEST),
+            ("up", Direction::UP),
+            ("down", Direction::DOWN),
+        ];
+
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(DirectionError::Empty);
+        }
+        if s.eq_ignore_ascii_case("none") || s.eq_ignore_ascii_case("no-direction") {
+            return Ok(Self::NONE);
+        }
+
+        let mut direction = Self::NONE;
+        let mut add = |name: &str, part: Direction| {
+            if direction.0 & part.0 != 0 {
+                return Err(DirectionError::Repeated(name.to_string()));
+            }
+            if direction.0 & part.opposite().0 != 0 {
+                return Err(DirectionError::Conflicting(name.to_string()));
+            }
+            direction.0 |= part.0;
+            Ok(())
+        };
+
+        let words = s
+            .split(|c: char| c == '_' || c == '-' || c == ',' || c.is_whitespace())
+            .filter(|word| !word.is_empty());
+        for word in words {
+            if let Some((name, part)) = PARTS
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(word))
+            {
+                add(name, *part)?;
+                continue;
+            }
+
+            // canonical initials such as `NE` or `unw`, up or down first, then north or south,
+            // then east or west
+            let mut rest = word.as_bytes();
+            for pair in [[4, 5], [0, 2], [1, 3]] {
+                let Some(initial) = rest.first().map(u8::to_ascii_lowercase) else {
+                    break;
+                };
+                let mut parts = pair.iter().map(|&index| PARTS[index]);
+                if let Some((name, part)) = parts.find(|(name, _)| name.as_bytes()[0] == initial) {
+                    add(name, part)?;
+                    rest = &rest[1..];
+                }
+            }
+            if !rest.is_empty() {
+                return Err(DirectionError::Unknown(word.to_string()));
+            }
+        }
+
+        Ok(direction)
+    }
+}
+
+impl From<Direction> for IVec2 {
+    fn from(direction: Direction) -> Self {
+        direction.as_ivec2()
+    }
+}
+
+impl From<Direction> for IVec3 {
+    fn from(direction: Direction) -> Self {
+        direction.as_ivec3()
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Self {
+        direction.as_vec2()
+    }
+}
+
+impl From<Direction> for Vec3 {
+    fn from(direction: Direction) -> Self {
+        direction.as_vec3()
+    }
+}
+
+impl From<IVec2> for Direction {
+    fn from(vec: IVec2) -> Self {
+        Self::from_coord((vec.x, vec.y))
+    }
+}
+
+impl From<IVec3> for Direction {
+    fn from(vec: IVec3) -> Self {
+        Self::from_coord3d((vec.x, vec.y, vec.z))
+    }
+}
+
+impl From<Dir2> for Direction {
+    fn from(dir: Dir2) -> Self {
+        Self::nearest_from_vec2(*dir)
+    }
+}
+
 #[allow(clippy::suspicious_arithmetic_impl)]
 impl Add<Self> for Direction {
     type Output = Self;
@@ -636,7 +1108,13 @@ impl BitXorAssign<Self> for Direction {
 
 #[cfg(test)]
 mod tests {
+    use bevy::prelude::*;
+
     use super::{CardinalDirection, Direction, OrdinalDirection, VerticalDirection};
+    use crate::{
+        direction::{DirectionError, YAxis},
+        grid::Grid,
+    };
 
     #[test]
     fn test_direction_const() {
@@ -808,6 +1286,168 @@ mod tests {
         assert_eq!(Direction::DOWN_NORTH_WEST.coord3d(), (-1, 1, -1));
     }
 
+    #[test]
+    fn test_direction_y_axis() {
+        assert_eq!(Direction::NORTH.coord_in(YAxis::Up), (0, 1));
+        assert_eq!(Direction::NORTH.coord_in(YAxis::Down), (0, -1));
+        assert_eq!(Direction::SOUTH_EAST.coord_in(YAxis::Down), (1, 1));
+        assert_eq!(Direction::UP_NORTH.coord3d_in(YAxis::Down), (0, -1, 1));
+        assert_eq!(
+            Direction::from_coord_in((-1, -1), YAxis::Down),
+            Direction::NORTH_WEST
+        );
+        assert_eq!(Direction::NORTH_EAST.flip_y(), Direction::SOUTH_EAST);
+
+        for y_axis in [YAxis::Up, YAxis::Down] {
+            for direction in Direction::iter_all_3d() {
+                assert_eq!(
+                    Direction::from_coord_in(direction.coord_in(y_axis), y_axis),
+                    direction - Direction::UP - Direction::DOWN
+                );
+                assert_eq!(
+                    Direction::from_coord3d_in(direction.coord3d_in(y_axis), y_axis),
+                    direction
+                );
+                assert_eq!(
+                    y_axis.to_y_up(direction).coord(),
+                    direction.coord_in(y_axis)
+                );
+            }
+        }
+
+        // row 0 is the top of the screen, so north is the row above
+        let mut rows = Grid::new_copy((3, 3), '.');
+        rows[Direction::NORTH.step_in((1, 1), YAxis::Down)] = '^';
+        let screen: Vec<String> = rows.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(screen, vec![".^.", "...", "..."]);
+    }
+
+    #[test]
+    fn test_direction_vectors() {
+        for direction in Direction::iter_all_3d() {
+            let (x, y) = direction.coord();
+            assert_eq!(direction.as_ivec2(), IVec2::new(x, y));
+            assert_eq!(Direction::from(direction.as_ivec3()), direction);
+            assert!((direction.as_vec3().length() - 1.0).abs() < 1e-6);
+            assert!(direction.as_dir3().is_some());
+        }
+        for direction in Direction::iter_cardinal_ordinal() {
+            let vec = direction.as_vec2();
+            assert!((vec.length() - 1.0).abs() < 1e-6);
+            assert_eq!(direction.as_dir2().map(|dir| *dir), Some(vec));
+            assert_eq!(Direction::nearest_from_vec2(vec), direction);
+            assert_eq!(Direction::from_angle(direction.angle()), direction);
+        }
+
+        assert_eq!(Direction::UP.as_vec2(), Vec2::ZERO);
+        assert_eq!(Direction::UP.as_dir2(), None);
+        assert_eq!(Direction::NONE.as_dir3(), None);
+        assert_eq!(Direction::NORTH.angle_degrees(), 90.0);
+        assert_eq!(Direction::SOUTH_WEST.angle_degrees(), -135.0);
+        assert_eq!(Vec2::from(Direction::WEST), Vec2::NEG_X);
+    }
+
+    #[test]
+    fn test_direction_snap() {
+        assert_eq!(
+            Direction::nearest_from_vec2(Vec2::new(1.0, 0.3)),
+            Direction::EAST
+        );
+        assert_eq!(
+            Direction::nearest_from_vec2(Vec2::new(1.0, 0.5)),
+            Direction::NORTH_EAST
+        );
+        assert_eq!(
+            Direction::nearest_from_vec2(Vec2::new(-0.2, -3.0)),
+            Direction::SOUTH
+        );
+        assert_eq!(
+            Direction::nearest_cardinal_from_vec2(Vec2::new(-1.0, 0.9)),
+            Direction::WEST
+        );
+        assert_eq!(
+            Direction::nearest_cardinal_from_vec2(Vec2::new(0.9, -1.0)),
+            Direction::SOUTH
+        );
+        assert_eq!(Direction::nearest_from_vec2(Vec2::ZERO), Direction::NONE);
+        assert_eq!(Direction::from_angle(f32::NAN), Direction::NONE);
+
+        // wraps around
+        assert_eq!(Direction::from_angle(-0.1), Direction::EAST);
+        assert_eq!(
+            Direction::from_angle(std::f32::consts::TAU - 0.1),
+            Direction::EAST
+        );
+        assert_eq!(
+            Direction::from_angle_cardinal(3.0_f32.to_radians() + std::f32::consts::PI),
+            Direction::WEST
+        );
+        assert_eq!(Direction::from(Dir2::NEG_Y), Direction::SOUTH);
+    }
+
+    #[test]
+    fn test_direction_toward() {
+        assert_eq!(Direction::toward((0, 0), (5, 1)), Direction::NORTH_EAST);
+        assert_eq!(Direction::toward((2, 2), (2, -7)), Direction::SOUTH);
+        assert_eq!(Direction::toward((1, 1), (1, 1)), Direction::NONE);
+        assert_eq!(
+            Direction::toward3d((0, 0, 0), (-3, 0, 2)),
+            Direction::UP_WEST
+        );
+
+        assert_eq!(Direction::toward_cardinal((0, 0), (5, 1)), Direction::EAST);
+        assert_eq!(
+            Direction::toward_cardinal((0, 0), (1, -5)),
+            Direction::SOUTH
+        );
+        assert_eq!(Direction::toward_cardinal((0, 0), (-3, 3)), Direction::WEST);
+
+        assert_eq!(
+            Direction::approximate_between((0, 0), (5, 1)),
+            Direction::EAST
+        );
+        assert_eq!(
+            Direction::approximate_between((0, 0), (5, 4)),
+            Direction::NORTH_EAST
+        );
+
+        assert_eq!(Direction::NORTH_EAST.step((3, 3)), (4, 4));
+        assert_eq!(Direction::DOWN_WEST.step3d((3, 3, 3)), (2, 3, 2));
+        assert_eq!(Direction::UP.step((3, 3)), (3, 3));
+    }
+
+    #[test]
+    fn test_direction_preferred_toward() {
+        // slightly north of east, so north east beats south east
+        let preferred: Vec<Direction> = Direction::preferred_toward((0, 0), (5, 1)).collect();
+        assert_eq!(
+            preferred,
+            vec![
+                Direction::EAST,
+                Direction::NORTH_EAST,
+                Direction::SOUTH_EAST,
+                Direction::NORTH,
+                Direction::SOUTH,
+                Direction::NORTH_WEST,
+                Direction::SOUTH_WEST,
+                Direction::WEST,
+            ]
+        );
+
+        let preferred: Vec<Direction> = Direction::preferred_toward((0, 0), (-1, -4)).collect();
+        assert_eq!(
+            preferred[..3],
+            [
+                Direction::SOUTH,
+                Direction::SOUTH_WEST,
+                Direction::SOUTH_EAST
+            ]
+        );
+        assert_eq!(preferred.last(), Some(&Direction::NORTH));
+
+        assert_eq!(Direction::preferred_toward((2, 2), (2, 2)).count(), 0);
+    }
+
     #[test]
     fn test_direction_rotation() {
         // Test left rotation
@@ -842,6 +1482,109 @@ mod tests {
         assert_eq!(Direction::UP.opposite(), Direction::DOWN);
     }
 
+    #[test]
+    fn test_direction_rotation_3d() {
+        assert_eq!(Direction::NORTH.rotate_x(1), Direction::UP_NORTH);
+        assert_eq!(Direction::NORTH.rotate_x(2), Direction::UP);
+        assert_eq!(Direction::UP.rotate_x(2), Direction::SOUTH);
+        assert_eq!(Direction::UP.rotate_y(2), Direction::EAST);
+        assert_eq!(Direction::EAST.rotate_y(2), Direction::DOWN);
+        assert_eq!(Direction::EAST.rotate_z(2), Direction::NORTH);
+        assert_eq!(
+            Direction::DOWN_SOUTH_WEST.rotate_x(-1),
+            Direction::SOUTH_WEST
+        );
+
+        let rotations: [fn(Direction, i32) -> Direction; 3] = [
+            Direction::rotate_x,
+            Direction::rotate_y,
+            Direction::rotate_z,
+        ];
+        for direction in Direction::iter_all_3d() {
+            assert_eq!(direction.rotate_z(1), direction.left45());
+            assert_eq!(direction.rotate_z(2), direction.left90());
+            assert_eq!(direction.rotate_z(-1), direction.right45());
+            assert_eq!(direction.rotate_z(-3), direction.right135());
+
+            for (axis, rotate) in rotations.into_iter().enumerate() {
+                let axis_of = |direction: Direction| {
+                    let (x, y, z) = direction.coord3d();
+                    [x, y, z][axis]
+                };
+                for steps in -8..=8 {
+                    let rotated = rotate(direction, steps);
+                    assert_eq!(axis_of(rotated), axis_of(direction));
+                    assert_eq!(rotate(rotated, -steps), direction);
+                    assert_eq!(rotate(rotate(direction, 1), steps - 1), rotated);
+                }
+                assert_eq!(rotate(direction, 4), {
+                    // half a turn flips the other two axes
+                    let (x, y, z) = direction.coord3d();
+                    let mut coord = [-x, -y, -z];
+                    coord[axis] = -coord[axis];
+                    Direction::from_coord3d((coord[0], coord[1], coord[2]))
+                });
+            }
+        }
+
+        // every rotation is a permutation of the 26 directions
+        for rotate in rotations {
+            for steps in 0..8 {
+                let rotated: std::collections::HashSet<Direction> = Direction::iter_all_3d()
+                    .map(|direction| rotate(direction, steps))
+                    .collect();
+                assert_eq!(rotated.len(), 26);
+            }
+        }
+    }
+
+    #[test]
+    fn test_direction_from_str() {
+        assert_eq!("N".parse(), Ok(Direction::NORTH));
+        assert_eq!("north".parse(), Ok(Direction::NORTH));
+        assert_eq!("North_East".parse(), Ok(Direction::NORTH_EAST));
+        assert_eq!("NE".parse(), Ok(Direction::NORTH_EAST));
+        assert_eq!("up-north-west".parse(), Ok(Direction::UP_NORTH_WEST));
+        assert_eq!(" down sw ".parse(), Ok(Direction::DOWN_SOUTH_WEST));
+        assert_eq!("none".parse(), Ok(Direction::NONE));
+
+        assert_eq!("".parse::<Direction>(), Err(DirectionError::Empty));
+        assert_eq!(
+            "northish".parse::<Direction>(),
+            Err(DirectionError::Unknown("northish".to_string()))
+        );
+        assert_eq!(
+            "north-south".parse::<Direction>(),
+            Err(DirectionError::Conflicting("south".to_string()))
+        );
+        assert_eq!(
+            "n-north".parse::<Direction>(),
+            Err(DirectionError::Repeated("north".to_string()))
+        );
+
+        // initials only in their canonical order, so words don't parse
+        assert_eq!("US".parse(), Ok(Direction::UP_SOUTH));
+        assert_eq!("dsw".parse(), Ok(Direction::DOWN_SOUTH_WEST));
+        for word in [
+            "sue", "new", "sun", "wed", "dune", "en", "su", "nn", "ud", "nse", "n1",
+        ] {
+            assert_eq!(
+                word.parse::<Direction>(),
+                Err(DirectionError::Unknown(word.to_string()))
+            );
+        }
+
+        // round trips through both names and `Display`
+        for direction in Direction::iter_all_3d() {
+            assert_eq!(direction.name().unwrap().parse(), Ok(direction));
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+        }
+        assert_eq!(Direction::NONE.to_string().parse(), Ok(Direction::NONE));
+        assert_eq!((Direction::NORTH + Direction::SOUTH).name(), None);
+        let unknown: Direction = ron::from_str("(200)").unwrap();
+        assert_eq!(unknown.name(), None);
+    }
+
     #[test]
     fn test_direction_iterators() {
         let cardinal_directions: Vec<Direction> = CardinalDirection::iter().collect();
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DirectionError {
+    #[error("Empty direction string")]
+    Empty,
+    #[error("Unknown direction `{0}`")]
+    Unknown(String),
+    #[error("Direction `{0}` appears more than once")]
+    Repeated(String),
+    #[error("Direction `{0}` conflicts with an opposite direction")]
+    Conflicting(String),
+    #[error("Directions `{0}` are not a rotation")]
+    InvalidTransform(String),
+    #[error("Bits `{0:#b}` are not a set of directions")]
+    InvalidSet(u32),
+}
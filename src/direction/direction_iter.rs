@@ -1,46 +1,113 @@
-use crate::direction::{Direction, DIRECTION_TABLE};
+use std::iter::FusedIterator;
 
+use rand::seq::SliceRandom;
+
+use crate::{
+    direction::{Direction, DIRECTION_TABLE},
+    random::Random,
+};
+
+/// An iterator over a list of [`Direction`]s
+#[derive(Debug, Clone)]
 pub struct DirectionIter {
-    current: usize,
-    end: usize,
+    directions: [Direction; 26],
+    front: usize,
+    back: usize,
 }
 
 impl DirectionIter {
-    /// Returns an iterator over the [`Direction`]s [`NORTH`, `NORTH_EAST`, `EAST`,
-    /// `SOUTH_EAST`, `SOUTH`, `SOUTH_WEST`, `WEST`, `NORTH_WEST`]
+    /// Returns an iterator over the [`Direction`]s [`NORTH`, `EAST`, `SOUTH`, `WEST`,
+    /// `NORTH_EAST`, `SOUTH_EAST`, `SOUTH_WEST`, `NORTH_WEST`]
+    ///
+    /// NOTE: This is not a clockwise order, see [`clockwise`](Self::clockwise).
     pub const fn cardinal_ordinal() -> Self {
-        Self { current: 0, end: 7 }
+        Self::from_table(0, 8)
     }
 
-    /// Returns an iterator over the [`Direction`]s [`NORTH`, `NORTH_EAST`, `EAST`,
-    /// `SOUTH_EAST`, `SOUTH`, `SOUTH_WEST`, `WEST`, `NORTH_WEST`, `UP`, `DOWN`]
+    /// Returns an iterator over the [`Direction`]s [`NORTH`, `EAST`, `SOUTH`, `WEST`,
+    /// `NORTH_EAST`, `SOUTH_EAST`, `SOUTH_WEST`, `NORTH_WEST`, `UP`, `DOWN`]
     pub const fn cardinal_ordinal_vertical() -> Self {
-        Self { current: 0, end: 9 }
+        Self::from_table(0, 10)
     }
 
     /// Returns an iterator over every [`Direction`] including every mutation of [`NORTH`,
     /// `EAST`, `SOUTH`, `WEST`, `UP`, `DOWN`]
     pub const fn all_3d() -> Self {
-        Self {
-            current: 0,
-            end: 25,
-        }
+        Self::from_table(0, 26)
     }
 
     /// Returns an iterator over the [`Direction`]s [`NORTH`, `EAST`, `SOUTH`, `WEST`]
     pub const fn cardinal() -> Self {
-        Self { current: 0, end: 3 }
+        Self::from_table(0, 4)
     }
 
     /// Returns an iterator over the [`Direction`]s [`NORTH_EAST`, `SOUTH_EAST`, `SOUTH_WEST`,
     /// `NORTH_WEST`]
     pub const fn ordinal() -> Self {
-        Self { current: 4, end: 7 }
+        Self::from_table(4, 8)
     }
 
     /// Returns an iterator over the [`Direction`]s (`UP`, `DOWN`)
     pub const fn vertical() -> Self {
-        Self { current: 8, end: 9 }
+        Self::from_table(8, 10)
+    }
+
+    /// Returns an iterator over the 8 cardinal and ordinal [`Direction`]s clockwise from
+    /// `start`, e.g. [`NORTH`, `NORTH_EAST`, `EAST`, ...]
+    ///
+    /// NOTE: Keeps the `UP` or `DOWN` of `start`, a `start` without a horizontal component
+    /// starts at `NORTH`.
+    pub fn clockwise(start: Direction) -> Self {
+        Self::around(start, -1)
+    }
+
+    /// Returns an iterator over the 8 cardinal and ordinal [`Direction`]s counter clockwise from
+    /// `start`, e.g. [`NORTH`, `NORTH_WEST`, `WEST`, ...]
+    ///
+    /// NOTE: Keeps the `UP` or `DOWN` of `start`, a `start` without a horizontal component
+    /// starts at `NORTH`.
+    pub fn counter_clockwise(start: Direction) -> Self {
+        Self::around(start, 1)
+    }
+
+    /// Returns the remaining [`Direction`]s in a random order
+    pub fn shuffled(mut self, random: &mut Random) -> Self {
+        self.directions[self.front..self.back].shuffle(&mut random.random);
+        self
+    }
+
+    /// Takes `DIRECTION_TABLE[start..end]`
+    const fn from_table(start: usize, end: usize) -> Self {
+        let mut directions = [Direction::NONE; 26];
+        let mut index = start;
+        while index < end {
+            directions[index - start] = DIRECTION_TABLE[index];
+            index += 1;
+        }
+        Self {
+            directions,
+            front: 0,
+            back: end - start,
+        }
+    }
+
+    /// Steps around the compass 45 degrees at a time, `step` is `1` for counter clockwise
+    fn around(start: Direction, step: i32) -> Self {
+        let start = if start.coord() == (0, 0) {
+            start + Direction::NORTH
+        } else {
+            start
+        };
+
+        let mut directions = [Direction::NONE; 26];
+        for (steps, direction) in (0..8).zip(directions.iter_mut()) {
+            *direction = start.rotate_z(steps * step);
+        }
+        Self {
+            directions,
+            front: 0,
+            back: 8,
+        }
     }
 }
 
@@ -48,12 +115,165 @@ impl Iterator for DirectionIter {
     type Item = Direction;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.current;
-        self.current += 1;
-        if next > self.end {
-            None
-        } else {
-            Some(DIRECTION_TABLE[next])
+        if self.front == self.back {
+            return None;
         }
+        self.front += 1;
+        Some(self.directions[self.front - 1])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for DirectionIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.directions[self.back])
+    }
+}
+
+impl ExactSizeIterator for DirectionIter {}
+
+impl FusedIterator for DirectionIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::DirectionIter;
+    use crate::{
+        direction::{Direction, DirectionSet},
+        random::Random,
+    };
+
+    #[test]
+    fn test_direction_iter_documented_order() {
+        use Direction as D;
+
+        let order = |iter: DirectionIter| iter.collect::<Vec<_>>();
+        assert_eq!(
+            order(DirectionIter::cardinal()),
+            [D::NORTH, D::EAST, D::SOUTH, D::WEST]
+        );
+        assert_eq!(
+            order(DirectionIter::ordinal()),
+            [D::NORTH_EAST, D::SOUTH_EAST, D::SOUTH_WEST, D::NORTH_WEST]
+        );
+        assert_eq!(order(DirectionIter::vertical()), [D::UP, D::DOWN]);
+        assert_eq!(
+            order(DirectionIter::cardinal_ordinal()),
+            [
+                D::NORTH,
+                D::EAST,
+                D::SOUTH,
+                D::WEST,
+                D::NORTH_EAST,
+                D::SOUTH_EAST,
+                D::SOUTH_WEST,
+                D::NORTH_WEST
+            ]
+        );
+        assert_eq!(
+            order(DirectionIter::cardinal_ordinal_vertical())[8..],
+            [D::UP, D::DOWN]
+        );
+        assert_eq!(DirectionIter::all_3d().len(), 26);
+
+        assert_eq!(
+            order(DirectionIter::clockwise(D::NORTH)),
+            [
+                D::NORTH,
+                D::NORTH_EAST,
+                D::EAST,
+                D::SOUTH_EAST,
+                D::SOUTH,
+                D::SOUTH_WEST,
+                D::WEST,
+                D::NORTH_WEST
+            ]
+        );
+        assert_eq!(
+            order(DirectionIter::counter_clockwise(D::NORTH)),
+            [
+                D::NORTH,
+                D::NORTH_WEST,
+                D::WEST,
+                D::SOUTH_WEST,
+                D::SOUTH,
+                D::SOUTH_EAST,
+                D::EAST,
+                D::NORTH_EAST
+            ]
+        );
+    }
+
+    #[test]
+    fn test_direction_iter_around() {
+        for start in Direction::iter_cardinal_ordinal() {
+            let clockwise: Vec<Direction> = DirectionIter::clockwise(start).collect();
+            assert_eq!(clockwise[0], start);
+            assert_eq!(clockwise[2], start.right90());
+            assert!(clockwise
+                .windows(2)
+                .all(|pair| pair[0].right45() == pair[1]));
+
+            // counter clockwise is clockwise backwards, after the start
+            let mut counter: Vec<Direction> = DirectionIter::counter_clockwise(start).collect();
+            counter[1..].reverse();
+            assert_eq!(counter, clockwise);
+        }
+
+        assert!(DirectionIter::clockwise(Direction::UP)
+            .eq(DirectionIter::clockwise(Direction::UP_NORTH)));
+        assert!(DirectionIter::clockwise(Direction::DOWN_EAST).all(|d| d.has_down()));
+    }
+
+    #[test]
+    fn test_direction_iter_double_ended() {
+        let mut iter = DirectionIter::cardinal();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next_back(), Some(Direction::WEST));
+        assert_eq!(iter.next(), Some(Direction::NORTH));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back(), Some(Direction::SOUTH));
+        assert_eq!(iter.next(), Some(Direction::EAST));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert!(DirectionIter::all_3d().rev().eq(DirectionIter::all_3d()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()));
+    }
+
+    #[test]
+    fn test_direction_iter_shuffled() {
+        let mut random = Random::new(7);
+        let mut firsts = [0; 8];
+        for _ in 0..8000 {
+            let shuffled: Vec<Direction> = DirectionIter::cardinal_ordinal()
+                .shuffled(&mut random)
+                .collect();
+            assert_eq!(
+                shuffled.iter().copied().collect::<DirectionSet>(),
+                DirectionSet::CARDINAL_ORDINAL
+            );
+            let first = Direction::iter_cardinal_ordinal()
+                .position(|d| d == shuffled[0])
+                .unwrap();
+            firsts[first] += 1;
+        }
+        // every direction leads about an eighth of the time
+        assert!(firsts.iter().all(|&count| (800..1200).contains(&count)));
+
+        // only what is left gets shuffled
+        let mut iter = DirectionIter::cardinal();
+        iter.next();
+        let rest: DirectionSet = iter.shuffled(&mut random).collect();
+        assert_eq!(rest, DirectionSet::CARDINAL.without(Direction::NORTH));
     }
 }
@@ -0,0 +1,120 @@
+//! Serializes a [`Direction`] by its [`name`](Direction::name) instead of its raw bits
+//!
+//! Opt in per field, numbers written by the default representation still load:
+//!
+//! ```
+//! use brt::direction::Direction;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Door {
+//!     #[serde(with = "brt::direction::direction_name")]
+//!     facing: Direction,
+//! }
+//!
+//! let door: Door = ron::from_str(r#"(facing: "north_east")"#).unwrap();
+//! assert_eq!(door.facing, Direction::NORTH_EAST);
+//! assert_eq!(ron::to_string(&door).unwrap(), r#"(facing:"north_east")"#);
+//! ```
+
+use std::fmt;
+
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+
+use crate::direction::Direction;
+
+/// Writes the name of `direction`, or its raw bits if it has no name
+pub fn serialize<S: Serializer>(direction: &Direction, serializer: S) -> Result<S::Ok, S::Error> {
+    match direction.name() {
+        Some(name) => serializer.serialize_str(name),
+        None => serializer.serialize_u8(direction.bits()),
+    }
+}
+
+/// Reads a [`Direction`] from any name [`FromStr`](std::str::FromStr) accepts, or from its raw
+/// bits
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Direction, D::Error> {
+    deserializer.deserialize_any(DirectionVisitor)
+}
+
+struct DirectionVisitor;
+
+impl<'de> Visitor<'de> for DirectionVisitor {
+    type Value = Direction;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a direction name or its bits")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        match u8::try_from(value) {
+            Ok(bits) if bits < 64 => Ok(Direction::from_bits(bits)),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+        }
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    // formats such as RON write the default `Direction(u8)` as a one element tuple
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let bits: u64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(2, &self));
+        }
+        self.visit_u64(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::direction::Direction;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Exit {
+        #[serde(with = "super")]
+        direction: Direction,
+    }
+
+    #[test]
+    fn test_direction_name_serde() {
+        for direction in Direction::iter_all_3d() {
+            let exit = Exit { direction };
+            let ron = ron::to_string(&exit).unwrap();
+            assert!(ron.contains(direction.name().unwrap()));
+            assert_eq!(ron::from_str::<Exit>(&ron).unwrap(), exit);
+
+            // the default representation still loads
+            let raw = ron::to_string(&direction).unwrap();
+            let old: Exit = ron::from_str(&format!("(direction: {raw})")).unwrap();
+            assert_eq!(old, exit);
+        }
+
+        let exit: Exit = ron::from_str(r#"(direction: "UP-NW")"#).unwrap();
+        assert_eq!(exit.direction, Direction::UP_NORTH_WEST);
+        assert!(ron::from_str::<Exit>(r#"(direction: "sideways")"#).is_err());
+        assert!(ron::from_str::<Exit>("(direction: 300)").is_err());
+    }
+}
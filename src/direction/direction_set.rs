@@ -0,0 +1,413 @@
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::direction::{
+    Direction, DirectionError, DirectionIter, DIRECTION_INDEX, DIRECTION_TABLE,
+};
+
+/// A set of [`Direction`]s, one bit for each of the 26 directions
+///
+/// NOTE: [`Direction::NONE`] and combinations such as `NORTH + SOUTH` are never in a set.
+#[derive(Serialize, Deserialize, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "DirectionSetBits", into = "DirectionSetBits")]
+pub struct DirectionSet(u32);
+
+/// The serialized form of a [`DirectionSet`], checked on the way in
+#[derive(Serialize, Deserialize)]
+struct DirectionSetBits(u32);
+
+impl TryFrom<DirectionSetBits> for DirectionSet {
+    type Error = DirectionError;
+
+    fn try_from(DirectionSetBits(bits): DirectionSetBits) -> Result<Self, Self::Error> {
+        match Self::from_bits(bits) {
+            set if set.0 == bits => Ok(set),
+            _ => Err(DirectionError::InvalidSet(bits)),
+        }
+    }
+}
+
+impl From<DirectionSet> for DirectionSetBits {
+    fn from(set: DirectionSet) -> Self {
+        Self(set.0)
+    }
+}
+
+// Constants
+impl DirectionSet {
+    /// Returns a [`DirectionSet`] containing every [`Direction`]
+    pub const ALL: Self = Self((1 << 26) - 1);
+    /// Returns a [`DirectionSet`] containing [`NORTH`, `EAST`, `SOUTH`, `WEST`]
+    pub const CARDINAL: Self = Self(0b1111);
+    /// Returns a [`DirectionSet`] containing every [`Direction`] without `UP` or `DOWN`
+    pub const CARDINAL_ORDINAL: Self = Self(0b1111_1111);
+    /// Returns an empty [`DirectionSet`]
+    pub const EMPTY: Self = Self(0);
+    /// Returns a [`DirectionSet`] containing [`NORTH_EAST`, `SOUTH_EAST`, `SOUTH_WEST`,
+    /// `NORTH_WEST`]
+    pub const ORDINAL: Self = Self(0b1111_0000);
+    /// Returns a [`DirectionSet`] containing [`UP`, `DOWN`]
+    pub const VERTICAL: Self = Self(0b11_0000_0000);
+}
+
+impl DirectionSet {
+    /// Creates a new empty [`DirectionSet`]
+    #[inline]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Creates a [`DirectionSet`] from a mask over the indices of the directions in
+    /// [`DirectionIter::all_3d`], unused bits are dropped
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    /// Get the mask over the indices of the directions in [`DirectionIter::all_3d`]
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Get the number of directions in the set
+    #[inline]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns `true` if the set holds no directions
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if the set holds `direction`
+    #[inline]
+    pub const fn contains(self, direction: Direction) -> bool {
+        self.0 & bit(direction) != 0
+    }
+
+    /// Adds `direction` to the set, returns `true` if it was not already in the set
+    #[inline]
+    pub fn insert(&mut self, direction: Direction) -> bool {
+        let bit = bit(direction);
+        let inserted = bit != 0 && self.0 & bit == 0;
+        self.0 |= bit;
+        inserted
+    }
+
+    /// Removes `direction` from the set, returns `true` if it was in the set
+    #[inline]
+    pub fn remove(&mut self, direction: Direction) -> bool {
+        let removed = self.contains(direction);
+        self.0 &= !bit(direction);
+        removed
+    }
+
+    /// Returns the set with `direction` added
+    #[inline]
+    pub const fn with(self, direction: Direction) -> Self {
+        Self(self.0 | bit(direction))
+    }
+
+    /// Returns the set with `direction` removed
+    #[inline]
+    pub const fn without(self, direction: Direction) -> Self {
+        Self(self.0 & !bit(direction))
+    }
+
+    /// Removes every direction from the set
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0 = 0;
+    }
+
+    /// Get an iterator over the directions in the set, in the order of [`DirectionIter::all_3d`]
+    #[inline]
+    pub const fn iter(self) -> DirectionSetIter {
+        DirectionSetIter { bits: self.0 }
+    }
+}
+
+// Set algebra
+impl DirectionSet {
+    /// Returns the directions in either set
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the directions in both sets
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the directions in `self` but not in `other`
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns the directions in exactly one of the sets
+    #[inline]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    /// Returns every direction not in the set
+    #[inline]
+    pub const fn complement(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+
+    /// Returns `true` if every direction in `self` is in `other`
+    #[inline]
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Returns `true` if every direction in `other` is in `self`
+    #[inline]
+    pub const fn is_superset(self, other: Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if the sets have no directions in common
+    #[inline]
+    pub const fn is_disjoint(self, other: Self) -> bool {
+        self.0 & other.0 == 0
+    }
+}
+
+/// Get the bit of `direction`, `0` if it is not in [`DIRECTION_TABLE`]
+const fn bit(direction: Direction) -> u32 {
+    let bits = direction.bits() as usize;
+    if bits >= DIRECTION_INDEX.len() {
+        return 0;
+    }
+    match DIRECTION_INDEX[bits] {
+        u8::MAX => 0,
+        index => 1 << index,
+    }
+}
+
+/// An iterator over the [`Direction`]s in a [`DirectionSet`]
+#[derive(Debug, Clone)]
+pub struct DirectionSetIter {
+    bits: u32,
+}
+
+impl Iterator for DirectionSetIter {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        Some(DIRECTION_TABLE[index as usize])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for DirectionSetIter {}
+
+impl IntoIterator for DirectionSet {
+    type IntoIter = DirectionSetIter;
+    type Item = Direction;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl From<Direction> for DirectionSet {
+    fn from(direction: Direction) -> Self {
+        Self::EMPTY.with(direction)
+    }
+}
+
+impl From<DirectionIter> for DirectionSet {
+    fn from(iter: DirectionIter) -> Self {
+        iter.collect()
+    }
+}
+
+impl FromIterator<Direction> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item = Direction>>(iter: I) -> Self {
+        let mut set = Self::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Direction> for DirectionSet {
+    fn extend<I: IntoIterator<Item = Direction>>(&mut self, iter: I) {
+        for direction in iter {
+            self.insert(direction);
+        }
+    }
+}
+
+impl BitOr<Self> for DirectionSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign<Self> for DirectionSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd<Self> for DirectionSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign<Self> for DirectionSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl BitXor<Self> for DirectionSet {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign<Self> for DirectionSet {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0;
+    }
+}
+
+impl Sub<Self> for DirectionSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign<Self> for DirectionSet {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 &= !rhs.0;
+    }
+}
+
+impl Not for DirectionSet {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DirectionSet;
+    use crate::direction::Direction;
+
+    #[test]
+    fn test_direction_set() {
+        let mut exits = DirectionSet::new();
+        assert!(exits.insert(Direction::NORTH));
+        assert!(exits.insert(Direction::SOUTH_EAST));
+        assert!(exits.insert(Direction::UP));
+        assert!(!exits.insert(Direction::UP));
+        assert!(!exits.insert(Direction::NONE));
+        assert!(!exits.insert(Direction::NORTH + Direction::SOUTH));
+        assert_eq!(exits.len(), 3);
+
+        assert!(exits.contains(Direction::SOUTH_EAST));
+        assert!(!exits.contains(Direction::UP_SOUTH_EAST));
+        assert!(exits.remove(Direction::NORTH));
+        assert!(!exits.remove(Direction::NORTH));
+        assert_eq!(
+            exits.iter().collect::<Vec<_>>(),
+            vec![Direction::SOUTH_EAST, Direction::UP]
+        );
+
+        // every direction has its own bit
+        let all: DirectionSet = Direction::iter_all_3d().collect();
+        assert_eq!(all, DirectionSet::ALL);
+        assert_eq!(all.iter().len(), 26);
+        assert!(all.iter().eq(Direction::iter_all_3d()));
+    }
+
+    #[test]
+    fn test_direction_set_algebra() {
+        assert_eq!(
+            DirectionSet::from(Direction::iter_cardinal()),
+            DirectionSet::CARDINAL
+        );
+        assert_eq!(
+            DirectionSet::from(Direction::iter_ordinal()),
+            DirectionSet::ORDINAL
+        );
+        assert_eq!(
+            DirectionSet::from(Direction::iter_vertical()),
+            DirectionSet::VERTICAL
+        );
+        assert_eq!(
+            DirectionSet::CARDINAL | DirectionSet::ORDINAL,
+            DirectionSet::CARDINAL_ORDINAL
+        );
+        assert_eq!(
+            DirectionSet::CARDINAL_ORDINAL - DirectionSet::CARDINAL,
+            DirectionSet::ORDINAL
+        );
+        assert!((DirectionSet::CARDINAL & DirectionSet::ORDINAL).is_empty());
+        assert!(DirectionSet::CARDINAL.is_disjoint(DirectionSet::VERTICAL));
+        assert!(DirectionSet::ORDINAL.is_subset(DirectionSet::CARDINAL_ORDINAL));
+        assert!(DirectionSet::ALL.is_superset(DirectionSet::VERTICAL));
+        assert_eq!((!DirectionSet::EMPTY), DirectionSet::ALL);
+        assert_eq!(
+            (!DirectionSet::CARDINAL_ORDINAL).len(),
+            DirectionSet::ALL.len() - 8
+        );
+        assert_eq!(
+            DirectionSet::CARDINAL ^ DirectionSet::from(Direction::NORTH),
+            DirectionSet::CARDINAL.without(Direction::NORTH)
+        );
+    }
+
+    #[test]
+    fn test_direction_set_serde() {
+        let set = DirectionSet::VERTICAL.with(Direction::WEST);
+        let ron = ron::to_string(&set).unwrap();
+        assert_eq!(ron::from_str::<DirectionSet>(&ron).unwrap(), set);
+
+        // only the 26 direction bits are a set
+        for bits in [1 << 26, u32::MAX] {
+            let error = ron::from_str::<DirectionSet>(&format!("({bits})")).unwrap_err();
+            assert!(error.to_string().contains("not a set"), "{error}");
+        }
+
+        // directions outside the table are never in a set
+        let direction: Direction = ron::from_str("(200)").unwrap();
+        assert!(!DirectionSet::ALL.contains(direction));
+        assert!(!DirectionSet::new().insert(direction));
+    }
+}
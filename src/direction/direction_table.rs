@@ -29,3 +29,50 @@ pub const DIRECTION_TABLE: &[Direction; 26] = &[
     Direction::DOWN_WEST,       // 24
     Direction::DOWN_NORTH_WEST, // 25
 ];
+
+/// The name of every [`Direction`] in [`DIRECTION_TABLE`], in the same order
+#[rustfmt::skip]
+pub const DIRECTION_NAMES: &[&str; 26] = &[
+    "north",
+    "east",
+    "south",
+    "west",
+    "north_east",
+    "south_east",
+    "south_west",
+    "north_west",
+    "up",
+    "down",
+    "up_north",
+    "up_north_east",
+    "up_east",
+    "up_south_east",
+    "up_south",
+    "up_south_west",
+    "up_west",
+    "up_north_west",
+    "down_north",
+    "down_north_east",
+    "down_east",
+    "down_south_east",
+    "down_south",
+    "down_south_west",
+    "down_west",
+    "down_north_west",
+];
+
+/// The index into [`DIRECTION_TABLE`] of every combination of [`DirectionFlags`], or `u8::MAX`
+/// for combinations which are not in the table.
+///
+/// [`DirectionFlags`]: crate::direction::DirectionFlags
+pub const DIRECTION_INDEX: [u8; 64] = direction_index_table();
+
+const fn direction_index_table() -> [u8; 64] {
+    let mut table = [u8::MAX; 64];
+    let mut index = 0;
+    while index < DIRECTION_TABLE.len() {
+        table[DIRECTION_TABLE[index].bits() as usize] = index as u8;
+        index += 1;
+    }
+    table
+}
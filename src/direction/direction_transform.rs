@@ -0,0 +1,297 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::direction::{Direction, DirectionError};
+
+/// An orientation made of 90 degree rotations, one of the 24 ways to turn a cube
+///
+/// Stores where `EAST`, `NORTH` and `UP` end up, every other [`Direction`] and coordinate
+/// follows from them.
+///
+/// NOTE: 45 degree steps don't compose, use [`Direction::rotate_x`] and friends for those.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "DirectionTransformAxes", into = "DirectionTransformAxes")]
+pub struct DirectionTransform {
+    x: Direction,
+    y: Direction,
+    z: Direction,
+}
+
+/// The serialized form of a [`DirectionTransform`], checked on the way in
+#[derive(Serialize, Deserialize)]
+struct DirectionTransformAxes {
+    x: Direction,
+    y: Direction,
+    z: Direction,
+}
+
+impl TryFrom<DirectionTransformAxes> for DirectionTransform {
+    type Error = DirectionError;
+
+    fn try_from(axes: DirectionTransformAxes) -> Result<Self, Self::Error> {
+        Self::looking(axes.y, axes.z)
+            .filter(|transform| transform.x == axes.x)
+            .ok_or_else(|| {
+                let name = |direction: Direction| direction.name().unwrap_or("?");
+                DirectionError::InvalidTransform(format!(
+                    "{}, {}, {}",
+                    name(axes.x),
+                    name(axes.y),
+                    name(axes.z)
+                ))
+            })
+    }
+}
+
+impl From<DirectionTransform> for DirectionTransformAxes {
+    fn from(transform: DirectionTransform) -> Self {
+        Self {
+            x: transform.x,
+            y: transform.y,
+            z: transform.z,
+        }
+    }
+}
+
+impl Default for DirectionTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl DirectionTransform {
+    /// Returns the [`DirectionTransform`] which changes nothing
+    pub const IDENTITY: Self = Self {
+        x: Direction::EAST,
+        y: Direction::NORTH,
+        z: Direction::UP,
+    };
+
+    /// Creates a rotation around the `X` (`EAST`) axis by `quarter_turns`, counter clockwise
+    /// when looking from `EAST`
+    pub fn rotation_x(quarter_turns: i32) -> Self {
+        Self::from_fn(|direction| direction.rotate_x(quarter_turns * 2))
+    }
+
+    /// Creates a rotation around the `Y` (`NORTH`) axis by `quarter_turns`, counter clockwise
+    /// when looking from `NORTH`
+    pub fn rotation_y(quarter_turns: i32) -> Self {
+        Self::from_fn(|direction| direction.rotate_y(quarter_turns * 2))
+    }
+
+    /// Creates a rotation around the `Z` (`UP`) axis by `quarter_turns`, counter clockwise when
+    /// looking from `UP`
+    pub fn rotation_z(quarter_turns: i32) -> Self {
+        Self::from_fn(|direction| direction.rotate_z(quarter_turns * 2))
+    }
+
+    /// Creates the orientation which turns `NORTH` to `forward` and `UP` to `up`
+    ///
+    /// Returns `None` unless both are distinct, perpendicular axis directions, e.g. `EAST` and
+    /// `DOWN`.
+    pub fn looking(forward: Direction, up: Direction) -> Option<Self> {
+        let (f, u) = (forward.coord3d(), up.coord3d());
+        let is_axis = |(x, y, z): (i32, i32, i32)| x.abs() + y.abs() + z.abs() == 1;
+        let dot = f.0 * u.0 + f.1 * u.1 + f.2 * u.2;
+        if !is_axis(f) || !is_axis(u) || dot != 0 {
+            return None;
+        }
+
+        // right handed, east = north x up
+        let x = (
+            f.1 * u.2 - f.2 * u.1,
+            f.2 * u.0 - f.0 * u.2,
+            f.0 * u.1 - f.1 * u.0,
+        );
+        Some(Self {
+            x: Direction::from_coord3d(x),
+            y: forward,
+            z: up,
+        })
+    }
+
+    fn from_fn(f: impl Fn(Direction) -> Direction) -> Self {
+        Self {
+            x: f(Direction::EAST),
+            y: f(Direction::NORTH),
+            z: f(Direction::UP),
+        }
+    }
+
+    /// Get where `EAST` ends up
+    #[inline]
+    pub const fn east(self) -> Direction {
+        self.x
+    }
+
+    /// Get where `NORTH` ends up
+    #[inline]
+    pub const fn north(self) -> Direction {
+        self.y
+    }
+
+    /// Get where `UP` ends up
+    #[inline]
+    pub const fn up(self) -> Direction {
+        self.z
+    }
+
+    /// Applies the transform to a 3d coordinate, rotating it around the origin
+    pub const fn apply_coord3d(self, coord: (i32, i32, i32)) -> (i32, i32, i32) {
+        let (x, y, z) = (self.x.coord3d(), self.y.coord3d(), self.z.coord3d());
+        (
+            coord.0 * x.0 + coord.1 * y.0 + coord.2 * z.0,
+            coord.0 * x.1 + coord.1 * y.1 + coord.2 * z.1,
+            coord.0 * x.2 + coord.1 * y.2 + coord.2 * z.2,
+        )
+    }
+
+    /// Applies the transform to a [`Direction`]
+    #[inline]
+    pub fn apply(self, direction: Direction) -> Direction {
+        Direction::from_coord3d(self.apply_coord3d(direction.coord3d()))
+    }
+
+    /// Returns the transform which applies `self` and then `other`
+    pub fn then(self, other: Self) -> Self {
+        Self {
+            x: other.apply(self.x),
+            y: other.apply(self.y),
+            z: other.apply(self.z),
+        }
+    }
+
+    /// Returns the transform which undoes `self`
+    pub fn inverse(self) -> Self {
+        // a rotation's inverse is its transpose
+        let (x, y, z) = (self.x.coord3d(), self.y.coord3d(), self.z.coord3d());
+        Self {
+            x: Direction::from_coord3d((x.0, y.0, z.0)),
+            y: Direction::from_coord3d((x.1, y.1, z.1)),
+            z: Direction::from_coord3d((x.2, y.2, z.2)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{DirectionTransform, DirectionTransformAxes};
+    use crate::direction::Direction;
+
+    /// Every orientation, built from quarter turns
+    fn orientations() -> HashSet<DirectionTransform> {
+        let mut orientations = HashSet::from([DirectionTransform::IDENTITY]);
+        loop {
+            let next: HashSet<DirectionTransform> = orientations
+                .iter()
+                .flat_map(|&transform| {
+                    [
+                        transform.then(DirectionTransform::rotation_x(1)),
+                        transform.then(DirectionTransform::rotation_y(1)),
+                        transform.then(DirectionTransform::rotation_z(1)),
+                    ]
+                })
+                .chain(orientations.iter().copied())
+                .collect();
+            if next.len() == orientations.len() {
+                return orientations;
+            }
+            orientations = next;
+        }
+    }
+
+    #[test]
+    fn test_direction_transform_rotations() {
+        for direction in Direction::iter_all_3d() {
+            for turns in -4..=4 {
+                assert_eq!(
+                    DirectionTransform::rotation_x(turns).apply(direction),
+                    direction.rotate_x(turns * 2)
+                );
+                assert_eq!(
+                    DirectionTransform::rotation_y(turns).apply(direction),
+                    direction.rotate_y(turns * 2)
+                );
+                assert_eq!(
+                    DirectionTransform::rotation_z(turns).apply(direction),
+                    direction.rotate_z(turns * 2)
+                );
+            }
+        }
+
+        assert_eq!(
+            DirectionTransform::rotation_z(1).apply_coord3d((2, 1, 5)),
+            (-1, 2, 5)
+        );
+        assert_eq!(
+            DirectionTransform::rotation_z(4),
+            DirectionTransform::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_direction_transform_group() {
+        let orientations = orientations();
+        assert_eq!(orientations.len(), 24);
+
+        for &a in &orientations {
+            assert_eq!(a.then(a.inverse()), DirectionTransform::IDENTITY);
+            assert_eq!(a.inverse().then(a), DirectionTransform::IDENTITY);
+            assert_eq!(DirectionTransform::looking(a.north(), a.up()), Some(a));
+
+            // a permutation of the 26 directions which keeps opposites opposite
+            let turned: HashSet<Direction> = Direction::iter_all_3d().map(|d| a.apply(d)).collect();
+            assert_eq!(turned.len(), 26);
+            for direction in Direction::iter_all_3d() {
+                assert_eq!(a.apply(direction.opposite()), a.apply(direction).opposite());
+            }
+
+            for &b in &orientations {
+                for direction in Direction::iter_all_3d() {
+                    assert_eq!(a.then(b).apply(direction), b.apply(a.apply(direction)));
+                }
+            }
+        }
+
+        assert_eq!(
+            DirectionTransform::looking(Direction::NORTH, Direction::NORTH),
+            None
+        );
+        assert_eq!(
+            DirectionTransform::looking(Direction::NORTH_EAST, Direction::UP),
+            None
+        );
+    }
+
+    #[test]
+    fn test_direction_transform_serde() {
+        let transform = DirectionTransform::looking(Direction::EAST, Direction::SOUTH).unwrap();
+        let ron = ron::to_string(&transform).unwrap();
+        assert_eq!(
+            ron::from_str::<DirectionTransform>(&ron).unwrap(),
+            transform
+        );
+
+        for transform in orientations() {
+            let ron = ron::to_string(&transform).unwrap();
+            assert_eq!(
+                ron::from_str::<DirectionTransform>(&ron).unwrap(),
+                transform
+            );
+        }
+
+        // only the 24 rotations load
+        for (x, y, z) in [
+            (Direction::NORTH, Direction::NORTH, Direction::UP),
+            (Direction::WEST, Direction::NORTH, Direction::UP),
+            (Direction::EAST, Direction::NORTH_EAST, Direction::UP),
+            (Direction::NONE, Direction::NONE, Direction::NONE),
+        ] {
+            let ron = ron::to_string(&DirectionTransformAxes { x, y, z }).unwrap();
+            let error = ron::from_str::<DirectionTransform>(&ron).unwrap_err();
+            assert!(error.to_string().contains("not a rotation"), "{error}");
+        }
+    }
+}
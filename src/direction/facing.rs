@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::direction::{Direction, RelativeDirection};
+
+/// The [`Direction`] an entity is facing
+#[derive(
+    Component,
+    Serialize,
+    Deserialize,
+    Reflect,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Deref,
+    DerefMut,
+)]
+#[reflect(Component)]
+pub struct Facing(pub Direction);
+
+impl Default for Facing {
+    fn default() -> Self {
+        Self(Direction::NORTH)
+    }
+}
+
+impl Facing {
+    /// Get the [`Direction`] which is `relative` to the facing
+    #[inline]
+    pub fn resolve(self, relative: RelativeDirection) -> Direction {
+        self.0.resolve(relative)
+    }
+
+    /// Get `direction` as seen from the facing
+    #[inline]
+    pub fn relative(self, direction: Direction) -> Option<RelativeDirection> {
+        direction.relative_to(self.0)
+    }
+
+    /// Get where `target` is relative to the facing of something standing at `position`
+    pub fn relative_position(
+        self,
+        position: (i32, i32),
+        target: (i32, i32),
+    ) -> Option<RelativeDirection> {
+        self.relative(Direction::approximate_between(position, target))
+    }
+}
+
+impl From<Direction> for Facing {
+    fn from(direction: Direction) -> Self {
+        Self(direction)
+    }
+}
+
+impl From<Facing> for Direction {
+    fn from(facing: Facing) -> Self {
+        facing.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Facing;
+    use crate::direction::{Direction, RelativeDirection};
+
+    #[test]
+    fn test_facing_relative_position() {
+        let guard = Facing(Direction::EAST);
+        assert_eq!(
+            guard.relative_position((0, 0), (5, 1)),
+            Some(RelativeDirection::Ahead)
+        );
+        assert_eq!(
+            guard.relative_position((0, 0), (-4, -3)),
+            Some(RelativeDirection::BehindRight)
+        );
+        assert!(guard
+            .relative_position((0, 0), (0, 3))
+            .is_some_and(RelativeDirection::is_flank));
+        assert_eq!(guard.relative_position((2, 2), (2, 2)), None);
+        assert_eq!(guard.resolve(RelativeDirection::Left), Direction::NORTH);
+    }
+}
@@ -1,3 +1,6 @@
+mod direction_error;
+pub use self::direction_error::*;
+
 mod direction_flags;
 pub(crate) use self::direction_flags::*;
 
@@ -9,3 +12,20 @@ pub(crate) use self::direction_table::*;
 
 mod direction;
 pub use self::direction::*;
+
+mod direction_set;
+pub use self::direction_set::*;
+
+mod direction_transform;
+pub use self::direction_transform::*;
+
+mod facing;
+pub use self::facing::*;
+
+mod relative_direction;
+pub use self::relative_direction::*;
+
+mod y_axis;
+pub use self::y_axis::*;
+
+pub mod direction_name;
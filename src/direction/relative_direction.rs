@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::direction::Direction;
+
+/// A [`Direction`] seen from something facing another [`Direction`]
+#[derive(Serialize, Deserialize, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeDirection {
+    /// The facing itself
+    #[default]
+    Ahead,
+    /// 45 degrees left of ahead
+    AheadLeft,
+    /// 90 degrees left of ahead
+    Left,
+    /// 135 degrees left of ahead
+    BehindLeft,
+    /// The opposite of the facing
+    Behind,
+    /// 135 degrees right of ahead
+    BehindRight,
+    /// 90 degrees right of ahead
+    Right,
+    /// 45 degrees right of ahead
+    AheadRight,
+}
+
+impl RelativeDirection {
+    /// Every [`RelativeDirection`], counter clockwise from `Ahead`
+    pub const ALL: [Self; 8] = [
+        Self::Ahead,
+        Self::AheadLeft,
+        Self::Left,
+        Self::BehindLeft,
+        Self::Behind,
+        Self::BehindRight,
+        Self::Right,
+        Self::AheadRight,
+    ];
+
+    /// Get the [`RelativeDirection`] `steps` of 45 degrees counter clockwise from `Ahead`
+    #[inline]
+    pub const fn from_steps(steps: i32) -> Self {
+        Self::ALL[steps.rem_euclid(8) as usize]
+    }
+
+    /// Get the number of 45 degree steps counter clockwise from `Ahead`
+    #[inline]
+    pub const fn steps(self) -> i32 {
+        self as i32
+    }
+
+    /// Returns the [`RelativeDirection`] on the other side, `AheadLeft` becomes `BehindRight`
+    #[inline]
+    pub const fn opposite(self) -> Self {
+        Self::from_steps(self.steps() + 4)
+    }
+
+    /// Returns `true` for `AheadLeft`, `Ahead` and `AheadRight`
+    #[inline]
+    pub const fn is_front(self) -> bool {
+        matches!(self, Self::AheadLeft | Self::Ahead | Self::AheadRight)
+    }
+
+    /// Returns `true` for `Left` and `Right`
+    #[inline]
+    pub const fn is_flank(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    /// Returns `true` for `BehindLeft`, `Behind` and `BehindRight`
+    #[inline]
+    pub const fn is_behind(self) -> bool {
+        matches!(self, Self::BehindLeft | Self::Behind | Self::BehindRight)
+    }
+}
+
+// Relative
+impl Direction {
+    /// Get this [`Direction`] as seen from something facing `facing`, ignoring `UP` and `DOWN`
+    ///
+    /// Returns `None` if either has no horizontal component.
+    pub fn relative_to(self, facing: Direction) -> Option<RelativeDirection> {
+        let target = self.coord();
+        if target == (0, 0) || facing.coord() == (0, 0) {
+            return None;
+        }
+        (0..8)
+            .find(|&steps| facing.rotate_z(steps).coord() == target)
+            .map(RelativeDirection::from_steps)
+    }
+
+    /// Get the [`Direction`] which is `relative` to this facing, the inverse of
+    /// [`relative_to`](Self::relative_to)
+    ///
+    /// NOTE: Keeps the `UP` or `DOWN` of the facing, a facing without a horizontal component
+    /// stays as is.
+    #[inline]
+    pub fn resolve(self, relative: RelativeDirection) -> Direction {
+        self.rotate_z(relative.steps())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelativeDirection;
+    use crate::direction::Direction;
+
+    #[test]
+    fn test_relative_direction() {
+        assert_eq!(
+            Direction::WEST.relative_to(Direction::NORTH),
+            Some(RelativeDirection::Left)
+        );
+        assert_eq!(
+            Direction::SOUTH_EAST.relative_to(Direction::NORTH),
+            Some(RelativeDirection::BehindRight)
+        );
+        assert_eq!(
+            Direction::UP_EAST.relative_to(Direction::EAST),
+            Some(RelativeDirection::Ahead)
+        );
+        assert_eq!(Direction::UP.relative_to(Direction::EAST), None);
+        assert_eq!(Direction::EAST.relative_to(Direction::NONE), None);
+        assert_eq!(
+            Direction::EAST.resolve(RelativeDirection::AheadLeft),
+            Direction::NORTH_EAST
+        );
+
+        for facing in Direction::iter_cardinal_ordinal() {
+            for relative in RelativeDirection::ALL {
+                let direction = facing.resolve(relative);
+                assert_eq!(direction.relative_to(facing), Some(relative));
+                assert_eq!(
+                    direction.opposite().relative_to(facing),
+                    Some(relative.opposite())
+                );
+            }
+        }
+
+        let front = RelativeDirection::ALL
+            .iter()
+            .filter(|r| r.is_front())
+            .count();
+        let flank = RelativeDirection::ALL
+            .iter()
+            .filter(|r| r.is_flank())
+            .count();
+        let behind = RelativeDirection::ALL
+            .iter()
+            .filter(|r| r.is_behind())
+            .count();
+        assert_eq!((front, flank, behind), (3, 2, 3));
+    }
+}
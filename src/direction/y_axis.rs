@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::direction::Direction;
+
+/// Which way `NORTH` points along the `Y` axis
+///
+/// [`Direction::coord`] and friends use `Up`, pass `Down` to the `*_in` variants for grids where
+/// row `0` is the top of the screen.
+#[derive(Serialize, Deserialize, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YAxis {
+    /// `NORTH` is `+Y`, the math and Bevy world convention
+    #[default]
+    Up,
+    /// `NORTH` is `-Y`, the screen, terminal and row major convention
+    Down,
+}
+
+impl YAxis {
+    /// Get the sign of `NORTH` on the `Y` axis
+    #[inline]
+    pub const fn north_sign(self) -> i32 {
+        match self {
+            Self::Up => 1,
+            Self::Down => -1,
+        }
+    }
+
+    /// Converts a coordinate between this convention and `Up`, converting twice is a no-op
+    #[inline]
+    pub const fn convert(self, coord: (i32, i32)) -> (i32, i32) {
+        (coord.0, coord.1 * self.north_sign())
+    }
+
+    /// Get the [`Direction`] pointing the same way on the `Y` up grid as `direction` does on
+    /// this convention's grid, swapping `NORTH` and `SOUTH` for `Down`
+    #[inline]
+    pub fn to_y_up(self, direction: Direction) -> Direction {
+        match self {
+            Self::Up => direction,
+            Self::Down => direction.flip_y(),
+        }
+    }
+}
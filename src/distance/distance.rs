@@ -1,3 +1,12 @@
+use std::f32::consts::SQRT_2;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A way to measure the distance between two points
+///
+/// Loads from RON by name, e.g. `Octile` or `DiagonalWithCosts(1.0, 1.5)`.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
 pub enum Distance {
     /// Use the Pythagoras algorithm for determining distance - sqrt(A^2 + B^2)
     Pythagoras,
@@ -12,6 +21,10 @@ pub enum Distance {
     Diagonal,
     /// Use a diagonal distance, the max of the x and y distances
     DiagonalWithCosts(f32, f32),
+    /// Use a diagonal distance where a diagonal step costs `sqrt(2)`
+    Octile,
+    /// Use the number of steps on a hex grid between axial `(q, r)` coordinates
+    Hex,
 }
 
 impl Distance {
@@ -23,10 +36,132 @@ impl Distance {
             Distance::Chebyshev => chebyshev(start, end),
             Distance::Diagonal => diagonal(start, end),
             Distance::DiagonalWithCosts(d1, d2) => diagonal_with_costs(start, end, d1, d2),
+            Distance::Octile => diagonal_with_costs(start, end, 1.0, SQRT_2),
+            Distance::Hex => hex(start, end),
+        }
+    }
+
+    /// Calculates the distance between grid positions, exactly until the final conversion
+    pub fn calculate_i32(self, start: (i32, i32), end: (i32, i32)) -> f32 {
+        let dx = (end.0 as i128 - start.0 as i128).abs();
+        let dy = (end.1 as i128 - start.1 as i128).abs();
+        match self {
+            Distance::Pythagoras => ((dx * dx + dy * dy) as f64).sqrt() as f32,
+            Distance::PythagorasSquared => (dx * dx + dy * dy) as f32,
+            Distance::Manhattan => (dx + dy) as f32,
+            Distance::Chebyshev | Distance::Diagonal => dx.max(dy) as f32,
+            Distance::DiagonalWithCosts(d1, d2) => {
+                d1.mul_add(dx.max(dy) as f32, (d2 - d1) * dx.min(dy) as f32)
+            }
+            Distance::Octile => (dx.max(dy) - dx.min(dy)) as f32 + SQRT_2 * dx.min(dy) as f32,
+            Distance::Hex => {
+                let (dq, dr) = (end.0 as i64 - start.0 as i64, end.1 as i64 - start.1 as i64);
+                ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as f32
+            }
+        }
+    }
+
+    /// Calculates the distance between 3d grid positions, exactly until the final conversion
+    ///
+    /// `Diagonal` counts every step to a neighbour as one, `DiagonalWithCosts` prices a step
+    /// along two or three axes as an ordinal step and `Octile` prices them `sqrt(2)` and
+    /// `sqrt(3)`. `Hex` adds the `Z` distance to the hex distance of each layer.
+    pub fn calculate3d_i32(self, start: (i32, i32, i32), end: (i32, i32, i32)) -> f32 {
+        let dx = (end.0 as i128 - start.0 as i128).abs();
+        let dy = (end.1 as i128 - start.1 as i128).abs();
+        let dz = (end.2 as i128 - start.2 as i128).abs();
+        let mut sorted = [dx, dy, dz];
+        sorted.sort_unstable();
+        let [low, mid, high] = sorted;
+
+        match self {
+            Distance::Pythagoras => ((dx * dx + dy * dy + dz * dz) as f64).sqrt() as f32,
+            Distance::PythagorasSquared => (dx * dx + dy * dy + dz * dz) as f32,
+            Distance::Manhattan => (dx + dy + dz) as f32,
+            Distance::Chebyshev | Distance::Diagonal => high as f32,
+            Distance::DiagonalWithCosts(d1, d2) => d1.mul_add((high - mid) as f32, d2 * mid as f32),
+            Distance::Octile => {
+                (high - mid) as f32 + SQRT_2 * (mid - low) as f32 + 3.0f32.sqrt() * low as f32
+            }
+            Distance::Hex => {
+                Distance::Hex.calculate_i32((start.0, start.1), (end.0, end.1)) + dz as f32
+            }
         }
     }
 }
 
+// Range
+impl Distance {
+    /// Returns `true` if `end` is no further than `radius` from `start`
+    ///
+    /// `Pythagoras` compares exactly on integers, `PythagorasSquared` is compared to `radius²`
+    /// so both give the same disc.
+    pub fn within_radius(self, start: (i32, i32), end: (i32, i32), radius: u32) -> bool {
+        match self {
+            Distance::Pythagoras | Distance::PythagorasSquared => {
+                let dx = end.0 as i128 - start.0 as i128;
+                let dy = end.1 as i128 - start.1 as i128;
+                dx * dx + dy * dy <= radius as i128 * radius as i128
+            }
+            _ => self.calculate_i32(start, end) <= radius as f32,
+        }
+    }
+
+    /// Get how far along either axis a point within `radius` can be
+    ///
+    /// NOTE: `DiagonalWithCosts` with a cost of `0` or less reaches no further than `radius`,
+    /// so [`DistanceRange`](crate::grid_shapes::DistanceRange) and
+    /// [`DistanceRing`](crate::grid_shapes::DistanceRing) clip it to a square.
+    pub fn reach(self, radius: u32) -> u32 {
+        match self {
+            Distance::DiagonalWithCosts(d1, d2) if d1.min(d2) > 0.0 => {
+                (radius as f32 / d1.min(d2)).floor() as u32
+            }
+            _ => radius,
+        }
+    }
+}
+
+/// A cost estimate between two grid positions, such as a [`Distance`] or a closure
+pub trait Heuristic {
+    /// returns the estimated cost of getting from `start` to `end`
+    fn cost(&self, start: (i32, i32), end: (i32, i32)) -> f32;
+}
+
+impl Heuristic for Distance {
+    #[inline]
+    fn cost(&self, start: (i32, i32), end: (i32, i32)) -> f32 {
+        self.calculate_i32(start, end)
+    }
+}
+
+impl<F: Fn((i32, i32), (i32, i32)) -> f32> Heuristic for F {
+    #[inline]
+    fn cost(&self, start: (i32, i32), end: (i32, i32)) -> f32 {
+        self(start, end)
+    }
+}
+
+/// A cost estimate between two 3d grid positions, such as a [`Distance`] or a closure
+pub trait Heuristic3 {
+    /// returns the estimated cost of getting from `start` to `end`
+    fn cost3d(&self, start: (i32, i32, i32), end: (i32, i32, i32)) -> f32;
+}
+
+impl Heuristic3 for Distance {
+    #[inline]
+    fn cost3d(&self, start: (i32, i32, i32), end: (i32, i32, i32)) -> f32 {
+        self.calculate3d_i32(start, end)
+    }
+}
+
+impl<F: Fn((i32, i32, i32), (i32, i32, i32)) -> f32> Heuristic3 for F {
+    #[inline]
+    fn cost3d(&self, start: (i32, i32, i32), end: (i32, i32, i32)) -> f32 {
+        self(start, end)
+    }
+}
+
 fn pythagoras(start: (f32, f32), end: (f32, f32)) -> f32 {
     pythagoras_squared(start, end).sqrt()
 }
@@ -62,9 +197,16 @@ fn diagonal_with_costs(
     cardinal_cost.mul_add(dx.max(dy), (ordinal_cost - cardinal_cost) * dx.min(dy))
 }
 
+fn hex(start: (f32, f32), end: (f32, f32)) -> f32 {
+    let dq = end.0 - start.0;
+    let dr = end.1 - start.1;
+    (dq.abs() + dr.abs() + (dq + dr).abs()) / 2.0
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Distance;
+    use super::{Distance, Heuristic, Heuristic3};
+    use crate::hex::Hex;
 
     #[test]
     fn test_distances() {
@@ -93,4 +235,143 @@ mod tests {
         let difference = diagonal_with_costs - 20.0;
         assert!(difference <= f32::EPSILON);
     }
+
+    #[test]
+    fn test_distances_i32() {
+        let metrics = [
+            Distance::Pythagoras,
+            Distance::PythagorasSquared,
+            Distance::Manhattan,
+            Distance::Chebyshev,
+            Distance::Diagonal,
+            Distance::DiagonalWithCosts(1.0, 1.5),
+            Distance::Octile,
+            Distance::Hex,
+        ];
+        for metric in metrics {
+            for (start, end) in [((0, 0), (3, -4)), ((-7, 2), (5, 9)), ((1, 1), (1, 1))] {
+                let float = metric.calculate(
+                    (start.0 as f32, start.1 as f32),
+                    (end.0 as f32, end.1 as f32),
+                );
+                assert!((metric.calculate_i32(start, end) - float).abs() < 1e-4);
+                assert_eq!(metric.cost(start, end), metric.cost(end, start));
+
+                // a flat 3d distance is the 2d distance
+                let flat = metric.calculate3d_i32((start.0, start.1, 2), (end.0, end.1, 2));
+                assert!((flat - metric.calculate_i32(start, end)).abs() < 1e-4);
+            }
+        }
+
+        // exact where f32 coordinates are not
+        let far = (i32::MAX, 0);
+        assert_eq!(
+            Distance::Manhattan.calculate_i32((-1, 0), far),
+            i32::MAX as f32 + 1.0
+        );
+        assert_eq!(
+            Distance::PythagorasSquared.calculate_i32((16_777_216, 0), (16_777_217, 0)),
+            1.0
+        );
+
+        // squares of the widest spans do not overflow
+        let span = (i32::MAX as f32 - i32::MIN as f32).powi(2);
+        assert_eq!(
+            Distance::PythagorasSquared.calculate_i32((i32::MIN, i32::MIN), (0, 0)),
+            2.0 * (i32::MIN as f32).powi(2)
+        );
+        assert_eq!(
+            Distance::PythagorasSquared.calculate_i32((i32::MIN, i32::MIN), (i32::MAX, i32::MAX)),
+            2.0 * span
+        );
+        assert_eq!(
+            Distance::PythagorasSquared.calculate3d_i32(
+                (i32::MIN, i32::MIN, i32::MIN),
+                (i32::MAX, i32::MAX, i32::MAX)
+            ),
+            3.0 * span
+        );
+        let diagonal =
+            Distance::Pythagoras.calculate_i32((i32::MIN, i32::MIN), (i32::MAX, i32::MAX));
+        assert!((diagonal / span.sqrt() - std::f32::consts::SQRT_2).abs() < 1e-6);
+
+        let octile = Distance::Octile.calculate_i32((0, 0), (5, 2));
+        assert!((octile - (3.0 + 2.0 * std::f32::consts::SQRT_2)).abs() < 1e-5);
+
+        for (q, r) in [(3, -1), (-2, -2), (0, 4)] {
+            assert_eq!(
+                Distance::Hex.calculate_i32((0, 0), (q, r)),
+                Hex::new(q, r).length() as f32
+            );
+        }
+    }
+
+    #[test]
+    fn test_within_radius() {
+        for distance in [Distance::Pythagoras, Distance::PythagorasSquared] {
+            assert!(distance.within_radius((0, 0), (3, 4), 5));
+            assert!(!distance.within_radius((0, 0), (3, 5), 5));
+            // squares of the widest spans do not overflow
+            assert!(!distance.within_radius((i32::MIN, 0), (i32::MAX, 0), 5));
+            assert!(!distance.within_radius((i32::MIN, i32::MIN), (i32::MAX, i32::MAX), u32::MAX));
+            assert!(distance.within_radius((i32::MIN, 0), (i32::MAX, 0), u32::MAX));
+        }
+    }
+
+    #[test]
+    fn test_distances_3d() {
+        let start = (0, 0, 0);
+        let end = (4, -2, 1);
+        assert_eq!(Distance::Manhattan.calculate3d_i32(start, end), 7.0);
+        assert_eq!(Distance::Chebyshev.calculate3d_i32(start, end), 4.0);
+        assert_eq!(
+            Distance::PythagorasSquared.calculate3d_i32(start, end),
+            21.0
+        );
+        assert_eq!(
+            Distance::DiagonalWithCosts(1.0, 2.0).calculate3d_i32(start, end),
+            2.0 + 2.0 * 2.0
+        );
+        let octile = Distance::Octile.calculate3d_i32(start, end);
+        let expected = 2.0 + std::f32::consts::SQRT_2 + 3.0f32.sqrt();
+        assert!((octile - expected).abs() < 1e-5);
+        assert_eq!(Distance::Hex.calculate3d_i32(start, (3, -1, -2)), 5.0);
+    }
+
+    #[test]
+    fn test_heuristic() {
+        fn total(heuristic: &impl Heuristic, path: &[(i32, i32)]) -> f32 {
+            path.windows(2)
+                .map(|pair| heuristic.cost(pair[0], pair[1]))
+                .sum()
+        }
+
+        let path = [(0, 0), (1, 1), (3, 1)];
+        assert_eq!(total(&Distance::Manhattan, &path), 4.0);
+        assert_eq!(total(&|_, _| 10.0, &path), 20.0);
+        let weighted = |a: (i32, i32), b: (i32, i32)| Distance::Chebyshev.cost(a, b) * 2.0;
+        assert_eq!(total(&weighted, &path), 6.0);
+
+        let vertical_penalty = |a: (i32, i32, i32), b: (i32, i32, i32)| {
+            Distance::Manhattan.calculate3d_i32(a, b) + (b.2 - a.2).abs() as f32
+        };
+        assert_eq!(vertical_penalty.cost3d((0, 0, 0), (1, 0, 2)), 5.0);
+        assert_eq!(Distance::Diagonal.cost3d((0, 0, 0), (1, 0, 2)), 2.0);
+    }
+
+    #[test]
+    fn test_distance_ron() {
+        let metrics: Vec<Distance> =
+            ron::from_str("[Octile, Hex, DiagonalWithCosts(1.0, 1.5)]").unwrap();
+        assert_eq!(
+            metrics,
+            vec![
+                Distance::Octile,
+                Distance::Hex,
+                Distance::DiagonalWithCosts(1.0, 1.5)
+            ]
+        );
+        let round_trip: Vec<Distance> = ron::from_str(&ron::to_string(&metrics).unwrap()).unwrap();
+        assert_eq!(round_trip, metrics);
+    }
 }
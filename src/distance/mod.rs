@@ -1,2 +1,5 @@
 mod distance;
 pub use self::distance::*;
+
+mod range;
+pub use self::range::*;
@@ -0,0 +1,45 @@
+use crate::{
+    distance::Distance,
+    grid_shapes::{DistanceRange, DistanceRing, ShapeIter},
+};
+
+/// Returns an iterator over every point no further than `radius` from `center`
+///
+/// The points come from a [`DistanceRange`], which can be boxed as a shape too.
+#[inline]
+pub fn within(
+    center: (i32, i32),
+    radius: u32,
+    distance: Distance,
+) -> impl Iterator<Item = (i32, i32)> {
+    DistanceRange::new(center, radius, distance).iter()
+}
+
+/// Returns an iterator over every point exactly `radius` from `center`
+///
+/// The points come from a [`DistanceRing`], which can be boxed as a shape too.
+#[inline]
+pub fn ring(
+    center: (i32, i32),
+    radius: u32,
+    distance: Distance,
+) -> impl Iterator<Item = (i32, i32)> {
+    DistanceRing::new(center, radius, distance).iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::distance::{self, Distance};
+
+    #[test]
+    fn test_within_and_ring() {
+        let aura: Vec<(i32, i32)> = distance::within((0, 0), 1, Distance::Manhattan).collect();
+        assert_eq!(aura, [(0, -1), (-1, 0), (0, 0), (1, 0), (0, 1)]);
+
+        let edge: Vec<(i32, i32)> = distance::ring((0, 0), 1, Distance::Manhattan).collect();
+        assert_eq!(edge, [(0, -1), (-1, 0), (1, 0), (0, 1)]);
+
+        assert_eq!(distance::within((3, 3), 2, Distance::Chebyshev).count(), 25);
+        assert_eq!(distance::ring((3, 3), 2, Distance::Chebyshev).count(), 16);
+    }
+}
@@ -283,11 +283,11 @@ impl<T> Grid<T> {
 
 // Iterators
 impl<T> Grid<T> {
-    pub fn iter(&self) -> slice::Iter<T> {
+    pub fn iter(&self) -> slice::Iter<'_, T> {
         self.data.iter()
     }
 
-    pub fn iter_mut(&mut self) -> slice::IterMut<T> {
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
         self.data.iter_mut()
     }
 
@@ -321,7 +321,7 @@ impl<T> Grid<T> {
         PointIterRowMajor::new(self.size)
     }
 
-    pub fn row(&self, y: usize) -> Option<slice::Iter<T>> {
+    pub fn row(&self, y: usize) -> Option<slice::Iter<'_, T>> {
         if y < self.size.1 as usize {
             let start = y * self.size.0 as usize;
             Some(self.data[start..start + self.size.0 as usize].iter())
@@ -330,7 +330,7 @@ impl<T> Grid<T> {
         }
     }
 
-    pub fn row_mut(&mut self, y: usize) -> Option<slice::IterMut<T>> {
+    pub fn row_mut(&mut self, y: usize) -> Option<slice::IterMut<'_, T>> {
         if y < self.size.1 as usize {
             let start = y * self.size.0 as usize;
             Some(self.data[start..start + self.size.0 as usize].iter_mut())
@@ -339,15 +339,15 @@ impl<T> Grid<T> {
         }
     }
 
-    pub fn rows(&self) -> slice::ChunksExact<T> {
+    pub fn rows(&self) -> slice::ChunksExact<'_, T> {
         self.data.chunks_exact(self.size.0 as usize)
     }
 
-    pub fn rows_mut(&mut self) -> slice::ChunksExactMut<T> {
+    pub fn rows_mut(&mut self) -> slice::ChunksExactMut<'_, T> {
         self.data.chunks_exact_mut(self.size.0 as usize)
     }
 
-    pub fn column(&self, x: usize) -> Option<std::iter::StepBy<slice::Iter<T>>> {
+    pub fn column(&self, x: usize) -> Option<std::iter::StepBy<slice::Iter<'_, T>>> {
         if x < self.size.0 as usize {
             Some(self.data[x..].iter().step_by(self.size.0 as usize))
         } else {
@@ -355,7 +355,7 @@ impl<T> Grid<T> {
         }
     }
 
-    pub fn column_mut(&mut self, x: usize) -> Option<std::iter::StepBy<slice::IterMut<T>>> {
+    pub fn column_mut(&mut self, x: usize) -> Option<std::iter::StepBy<slice::IterMut<'_, T>>> {
         if x < self.size.0 as usize {
             Some(self.data[x..].iter_mut().step_by(self.size.0 as usize))
         } else {
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::{curve_contains, curve_samples, CurveIter, CurvePathIter},
+    BoxedShape, BoxedShapeIter, Circle, Rectangle, Shape, ShapeIter,
+};
+
+/// A circular arc around `center`, sweeping from `start_angle` to `end_angle`.
+///
+/// Angles are in radians, counter clockwise from `EAST`. The arc sweeps clockwise when
+/// `end_angle` is less than `start_angle`.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct Arc {
+    center: (i32, i32),
+    radius: u32,
+    start_angle: f32,
+    end_angle: f32,
+}
+
+impl Arc {
+    /// Creates a new arc.
+    pub fn new<R: Into<u32>>(
+        center: (i32, i32),
+        radius: R,
+        start_angle: f32,
+        end_angle: f32,
+    ) -> Self {
+        Self {
+            center,
+            radius: radius.into(),
+            start_angle,
+            end_angle,
+        }
+    }
+}
+
+impl Arc {
+    /// Get the center of the arc
+    #[inline]
+    pub const fn center(&self) -> (i32, i32) {
+        self.center
+    }
+
+    /// Get the radius of the arc
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// Get the angle the arc starts at
+    #[inline]
+    pub const fn start_angle(&self) -> f32 {
+        self.start_angle
+    }
+
+    /// Get the angle the arc ends at
+    #[inline]
+    pub const fn end_angle(&self) -> f32 {
+        self.end_angle
+    }
+
+    /// Get the point at `angle` on the arc's circle.
+    pub fn point_at(&self, angle: f32) -> (f32, f32) {
+        let radius = self.radius as f32;
+        (
+            self.center.0 as f32 + radius * angle.cos(),
+            self.center.1 as f32 + radius * angle.sin(),
+        )
+    }
+
+    /// Get an iterator over the connected path of the arc, from the start angle to the end
+    /// angle.
+    #[inline]
+    pub fn path(&self) -> CurvePathIter {
+        CurvePathIter::new(self.samples())
+    }
+
+    fn samples(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        let sweep = self.end_angle - self.start_angle;
+        let samples = curve_samples(self.radius as f32 * sweep.abs());
+        (0..=samples)
+            .map(move |i| self.point_at(self.start_angle + sweep * i as f32 / samples as f32))
+    }
+}
+
+impl Shape for Arc {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.iter().len() as u32
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.bounds().contains(position) && curve_contains(self.samples(), position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Circle::new(self.center, self.radius).bounds()
+    }
+}
+
+impl ShapeIter for Arc {
+    type Iterator = CurveIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        CurveIter::new(self.samples())
+    }
+}
+
+impl IntoIterator for Arc {
+    type IntoIter = CurveIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Display for Arc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Arc {{Center: ({}, {}), Radius: {}, Start Angle: {}, End Angle: {}}}",
+            self.center.0, self.center.1, self.radius, self.start_angle, self.end_angle
+        )
+    }
+}
+
+impl From<Arc> for BoxedShape {
+    fn from(value: Arc) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+    use super::Arc;
+    use crate::grid_shapes::{bezier::tests::assert_connected, Circle, Shape, ShapeIter};
+
+    #[test]
+    fn test_arc() {
+        let arc = Arc::new((0, 0), 8u32, 0.0, FRAC_PI_2);
+        assert_connected(&arc, arc.path());
+        let points: Vec<(i32, i32)> = arc.path().collect();
+        assert_eq!((points[0], points[points.len() - 1]), ((8, 0), (0, 8)));
+        assert!(points.iter().all(|p| p.0 >= 0 && p.1 >= 0));
+        assert_eq!(
+            arc.to_string(),
+            format!("Arc {{Center: (0, 0), Radius: 8, Start Angle: 0, End Angle: {FRAC_PI_2}}}")
+        );
+
+        // sweeping the other way covers the rest of the circle
+        let other = Arc::new((0, 0), 8u32, 0.0, FRAC_PI_2 - TAU);
+        assert_connected(&other, other.path());
+        assert!(other.contains((0, -8)) && other.contains((-8, 0)));
+        assert!(!other.contains((6, 6)));
+
+        let circle = Circle::new((0, 0), 8u32);
+        let full = Arc::new((0, 0), 8u32, -PI, PI);
+        assert!(full.iter().all(|p| circle.contains(p)));
+    }
+}
@@ -0,0 +1,322 @@
+use std::fmt::Display;
+
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::{curve_contains, curve_samples, CurveIter, CurvePathIter},
+    BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+};
+
+/// A quadratic Bezier curve from `start` to `end`, bending towards `control`.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct QuadraticBezier {
+    start: (i32, i32),
+    control: (i32, i32),
+    end: (i32, i32),
+}
+
+/// A cubic Bezier curve from `start` to `end`, bending towards `control1` then `control2`.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CubicBezier {
+    start: (i32, i32),
+    control1: (i32, i32),
+    control2: (i32, i32),
+    end: (i32, i32),
+}
+
+impl QuadraticBezier {
+    /// Creates a new quadratic Bezier curve.
+    #[inline(always)]
+    pub const fn new(start: (i32, i32), control: (i32, i32), end: (i32, i32)) -> Self {
+        Self {
+            start,
+            control,
+            end,
+        }
+    }
+
+    /// Get the point `t` of the way along the curve.
+    pub fn point_at(&self, t: f32) -> (f32, f32) {
+        let [a, b, c] = [self.start, self.control, self.end].map(as_f32);
+        let u = 1.0 - t;
+        (
+            u * u * a.0 + 2.0 * u * t * b.0 + t * t * c.0,
+            u * u * a.1 + 2.0 * u * t * b.1 + t * t * c.1,
+        )
+    }
+
+    /// Get an iterator over the connected path of the curve, from start to end.
+    #[inline]
+    pub fn path(&self) -> CurvePathIter {
+        CurvePathIter::new(self.samples())
+    }
+
+    fn samples(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        let samples = curve_samples(hull_length(&[self.start, self.control, self.end]));
+        (0..=samples).map(move |i| self.point_at(i as f32 / samples as f32))
+    }
+}
+
+impl CubicBezier {
+    /// Creates a new cubic Bezier curve.
+    #[inline(always)]
+    pub const fn new(
+        start: (i32, i32),
+        control1: (i32, i32),
+        control2: (i32, i32),
+        end: (i32, i32),
+    ) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+        }
+    }
+
+    /// Get the point `t` of the way along the curve.
+    pub fn point_at(&self, t: f32) -> (f32, f32) {
+        let [a, b, c, d] = [self.start, self.control1, self.control2, self.end].map(as_f32);
+        let u = 1.0 - t;
+        let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+        (
+            weights[0] * a.0 + weights[1] * b.0 + weights[2] * c.0 + weights[3] * d.0,
+            weights[0] * a.1 + weights[1] * b.1 + weights[2] * c.1 + weights[3] * d.1,
+        )
+    }
+
+    /// Get an iterator over the connected path of the curve, from start to end.
+    #[inline]
+    pub fn path(&self) -> CurvePathIter {
+        CurvePathIter::new(self.samples())
+    }
+
+    fn samples(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        let samples = curve_samples(hull_length(&[
+            self.start,
+            self.control1,
+            self.control2,
+            self.end,
+        ]));
+        (0..=samples).map(move |i| self.point_at(i as f32 / samples as f32))
+    }
+}
+
+impl Shape for QuadraticBezier {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.iter().len() as u32
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.bounds().contains(position) && curve_contains(self.samples(), position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        hull_bounds(&[self.start, self.control, self.end])
+    }
+}
+
+impl Shape for CubicBezier {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.iter().len() as u32
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.bounds().contains(position) && curve_contains(self.samples(), position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        hull_bounds(&[self.start, self.control1, self.control2, self.end])
+    }
+}
+
+impl ShapeIter for QuadraticBezier {
+    type Iterator = CurveIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        CurveIter::new(self.samples())
+    }
+}
+
+impl ShapeIter for CubicBezier {
+    type Iterator = CurveIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        CurveIter::new(self.samples())
+    }
+}
+
+impl IntoIterator for QuadraticBezier {
+    type IntoIter = CurveIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for CubicBezier {
+    type IntoIter = CurveIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl From<QuadraticBezier> for BoxedShape {
+    fn from(value: QuadraticBezier) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<CubicBezier> for BoxedShape {
+    fn from(value: CubicBezier) -> Self {
+        Box::new(value)
+    }
+}
+
+impl Display for QuadraticBezier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "QuadraticBezier {{Start: ({}, {}), Control: ({}, {}), End: ({}, {})}}",
+            self.start.0, self.start.1, self.control.0, self.control.1, self.end.0, self.end.1
+        )
+    }
+}
+
+impl Display for CubicBezier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CubicBezier {{Start: ({}, {}), Control1: ({}, {}), Control2: ({}, {}), End: ({}, {})}}",
+            self.start.0,
+            self.start.1,
+            self.control1.0,
+            self.control1.1,
+            self.control2.0,
+            self.control2.1,
+            self.end.0,
+            self.end.1
+        )
+    }
+}
+
+#[inline]
+fn as_f32(point: (i32, i32)) -> (f32, f32) {
+    (point.0 as f32, point.1 as f32)
+}
+
+/// The length of the lines between the control points, a curve is never longer.
+fn hull_length(points: &[(i32, i32)]) -> f32 {
+    points
+        .windows(2)
+        .map(|pair| {
+            let (a, b) = (as_f32(pair[0]), as_f32(pair[1]));
+            (b.0 - a.0).hypot(b.1 - a.1)
+        })
+        .sum()
+}
+
+/// The bounds of the control points, a curve never leaves them.
+fn hull_bounds(points: &[(i32, i32)]) -> Rectangle {
+    points
+        .iter()
+        .fold(Rectangle::new(points[0], points[0]), |bounds, &point| {
+            bounds.union(Rectangle::new(point, point))
+        })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashSet;
+
+    use super::{CubicBezier, QuadraticBezier};
+    use crate::{
+        direction::Direction,
+        grid_shapes::{Line, Rectangle, Shape, ShapeIter, ShapeTransform, Transformed, Union},
+    };
+
+    /// Checks every point of `path` is a neighbour of the point before it, and the shape
+    /// yields every point of the path once.
+    pub(crate) fn assert_connected<S: Shape + ShapeIter>(
+        shape: &S,
+        path: impl Iterator<Item = (i32, i32)>,
+    ) {
+        let path: Vec<(i32, i32)> = path.collect();
+        for pair in path.windows(2) {
+            let step = (pair[1].0 - pair[0].0, pair[1].1 - pair[0].1);
+            assert_eq!(step.0.abs().max(step.1.abs()), 1, "{pair:?}");
+        }
+
+        let points: Vec<(i32, i32)> = shape.iter().collect();
+        let unique: HashSet<(i32, i32)> = points.iter().copied().collect();
+        assert_eq!(points.len(), unique.len());
+        assert_eq!(points, shape.boxed_iter().collect::<Vec<_>>());
+        assert_eq!(unique, path.iter().copied().collect());
+        assert_eq!(points.len() as u32, shape.get_count());
+        for position in shape.bounds().expand(1) {
+            assert_eq!(shape.contains(position), unique.contains(&position));
+        }
+        assert!(points.iter().all(|&p| shape.bounds().contains(p)));
+    }
+
+    #[test]
+    fn test_bezier() {
+        let curve = QuadraticBezier::new((0, 0), (10, 20), (20, 0));
+        assert_connected(&curve, curve.path());
+        let points: Vec<(i32, i32)> = curve.path().collect();
+        assert_eq!((points[0], points[points.len() - 1]), ((0, 0), (20, 0)));
+        assert!(curve.contains((10, 10)));
+        assert_eq!(
+            curve.to_string(),
+            "QuadraticBezier {Start: (0, 0), Control: (10, 20), End: (20, 0)}"
+        );
+
+        let curve = CubicBezier::new((0, 0), (0, 15), (15, -15), (15, 0));
+        assert_connected(&curve, curve.path());
+        let points: Vec<(i32, i32)> = curve.path().collect();
+        assert_eq!((points[0], points[points.len() - 1]), ((0, 0), (15, 0)));
+
+        // folds back on itself
+        let curve = QuadraticBezier::new((0, 0), (10, 40), (1, 0));
+        assert_connected(&curve, curve.path());
+        assert!(curve.path().len() > curve.get_count() as usize);
+        let union = Union::new(curve, Line::new((0, 0), (0, 5)));
+        assert_eq!(union.iter().count() as u32, union.get_count());
+        let turned = Transformed::new(
+            curve,
+            ShapeTransform::IDENTITY.with_rotation(Direction::EAST),
+        );
+        assert_eq!(turned.iter().count() as u32, turned.get_count());
+        let curve = CubicBezier::new((0, 0), (20, 20), (-10, 20), (10, 0));
+        assert_connected(&curve, curve.path());
+
+        // a straight curve is a line
+        let curve = CubicBezier::new((0, 0), (3, 1), (6, 2), (9, 3));
+        assert_eq!(curve.get_count(), Line::new((0, 0), (9, 3)).get_count());
+        assert!(Rectangle::new((0, 0), (9, 3)).contains_rect(curve.bounds()));
+    }
+}
@@ -1,24 +1,60 @@
 use std::collections::HashSet;
 
-use bevy::prelude::Reflect;
+use bevy::prelude::{Reflect, ReflectDeserialize, ReflectSerialize};
 use serde::{Deserialize, Serialize};
 
-use crate::grid_shapes::{BoxedShape, BoxedShapeIter, Line, Shape, ShapeIter};
+use crate::{
+    direction::YAxis,
+    grid_shapes::{
+        iter::{CircleIter, CircumferenceIter},
+        BoxedShape, BoxedShapeIter, Line, Rectangle, Shape, ShapeIter, ShapeWithBorder,
+    },
+};
 
 /// A Grid based circle
+///
+/// Caches its point count, so it reflects as an opaque value and serializes without it.
 #[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(from = "CircleData", into = "CircleData")]
+#[reflect(opaque, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Circle {
     center: (i32, i32),
     radius: u32,
+    count: u32,
+}
+
+/// The serialized form of a [`Circle`]
+#[derive(Serialize, Deserialize)]
+struct CircleData {
+    center: (i32, i32),
+    radius: u32,
+}
+
+impl From<CircleData> for Circle {
+    fn from(data: CircleData) -> Self {
+        Self::new(data.center, data.radius)
+    }
+}
+
+impl From<Circle> for CircleData {
+    fn from(circle: Circle) -> Self {
+        Self {
+            center: circle.center,
+            radius: circle.radius,
+        }
+    }
 }
 
 impl Circle {
     /// Creates a new circle.
     pub fn new<R: Into<u32>>(center: (i32, i32), radius: R) -> Self {
-        Self {
+        let mut circle = Self {
             center,
             radius: radius.into(),
-        }
+            count: 0,
+        };
+        circle.count = circle.count_scanlines();
+        circle
     }
 }
 
@@ -29,6 +65,12 @@ impl Circle {
         self.center
     }
 
+    /// Get the radius of the circle
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+
     /// Get the left point of the circle
     #[inline]
     pub fn left(&self) -> (i32, i32) {
@@ -53,6 +95,24 @@ impl Circle {
         (self.center.0, self.center.1 - self.radius as i32)
     }
 
+    /// Get the top point of the circle with `NORTH` pointing along `y_axis`
+    #[inline]
+    pub fn top_in(&self, y_axis: YAxis) -> (i32, i32) {
+        match y_axis {
+            YAxis::Up => self.top(),
+            YAxis::Down => self.bottom(),
+        }
+    }
+
+    /// Get the bottom point of the circle with `NORTH` pointing along `y_axis`
+    #[inline]
+    pub fn bottom_in(&self, y_axis: YAxis) -> (i32, i32) {
+        match y_axis {
+            YAxis::Up => self.bottom(),
+            YAxis::Down => self.top(),
+        }
+    }
+
     /// Return a line from the left to the right of the circle
     #[inline]
     pub fn as_horizontal_line(&self) -> Line {
@@ -67,99 +127,215 @@ impl Circle {
 
     /// Get the circumfrence of the circle
     pub fn get_circumfrence(&self) -> HashSet<(i32, i32)> {
-        let mut discovered = HashSet::new();
-        let mut d = (5 - (self.radius as i32 * 4)) / 4;
-        let mut x = 0;
-        let mut y = self.radius as i32;
+        self.circumference_iter().collect()
+    }
 
-        loop {
-            // Circumfrence
-            discovered.insert((self.center.0 + x, self.center.1 + y));
-            discovered.insert((self.center.0 + x, self.center.1 - y));
-            discovered.insert((self.center.0 - x, self.center.1 + y));
-            discovered.insert((self.center.0 - x, self.center.1 - y));
-            discovered.insert((self.center.0 + y, self.center.1 + x));
-            discovered.insert((self.center.0 + y, self.center.1 - x));
-            discovered.insert((self.center.0 - y, self.center.1 + x));
-            discovered.insert((self.center.0 - y, self.center.1 - x));
+    /// Returns an iterator over the circumfrence of the circle
+    #[inline]
+    pub fn circumference_iter(&self) -> CircumferenceIter {
+        CircumferenceIter::new(*self)
+    }
 
-            if d < 0 {
-                d += (2 * x) + 1;
-            } else {
-                d += (2 * (x - y)) + 1;
-                y -= 1;
-            }
-            x += 1;
+    /// Get the distance from the center to the edge of the scanline `offset` rows away from the
+    /// center, or `None` if the scanline is outside of the circle.
+    pub(crate) fn half_width(&self, offset: i32) -> Option<i32> {
+        let radius = self.radius as i64;
+        let offset = offset.unsigned_abs() as i64;
+        if offset > radius {
+            return None;
+        }
 
-            if x > y {
-                break;
-            }
+        let y = self.octant_y(offset);
+        if y >= offset {
+            return Some(y as i32);
+        }
+
+        // the scanline is only reached by the mirrored octant, so find the last `x` at which
+        // the midpoint algorithm was still on this scanline
+        let v = self.decision_offset() - offset * offset - offset - 1;
+        if v >= 0 {
+            Some(1 + (v as u64).isqrt() as i32)
+        } else if offset == radius {
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    /// Get the `y` reached by the midpoint algorithm of `get_circumfrence` after stepping to
+    /// `x`, or `-1` if it never reaches `x`.
+    ///
+    /// While stepping `x`, the decision variable is always `x² + y² + y - k`, so the `y` at any
+    /// `x` can be solved for directly instead of walking the octant.
+    fn octant_y(&self, x: i64) -> i64 {
+        if x == 0 {
+            return self.radius as i64;
+        }
+        let m = self.decision_offset() - (x - 1) * (x - 1) - 1;
+        if m < 0 {
+            -1
+        } else {
+            ((4 * m as u64 + 1).isqrt() as i64 - 1) / 2
         }
-        discovered
+    }
+
+    /// Counts the points one scanline at a time without allocating.
+    ///
+    /// The points of a midpoint circle have no closed form count, so [`Circle::new`] does this
+    /// once. The rows above and below the center mirror each other, so only half are measured.
+    fn count_scanlines(&self) -> u32 {
+        let width = |offset| {
+            self.half_width(offset)
+                .map_or(0, |half_width| (half_width * 2 + 1) as u32)
+        };
+        width(0) + 2 * (1..=self.radius as i32).map(width).sum::<u32>()
+    }
+
+    /// Get `k` from `octant_y`
+    #[inline]
+    fn decision_offset(&self) -> i64 {
+        let radius = self.radius as i64;
+        let d = (5 - (radius * 4)) / 4;
+        radius * radius + radius - d
     }
 }
 
 impl Shape for Circle {
     #[inline]
-    // FIX: PERF??
     fn get_count(&self) -> u32 {
-        self.get_positions().len() as u32
+        self.count
     }
 
     #[inline]
     fn contains(&self, position: (i32, i32)) -> bool {
-        self.get_positions().contains(&position)
+        let dx = position.0 - self.center.0;
+        let dy = position.1 - self.center.1;
+        self.half_width(dy)
+            .is_some_and(|half_width| dx.abs() <= half_width)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
     }
 
-    // TODO: Move to iter()
     #[inline]
-    fn get_positions(&self) -> HashSet<(i32, i32)> {
+    fn bounds(&self) -> Rectangle {
+        let radius = self.radius as i32;
+        Rectangle::new(
+            (self.center.0 - radius, self.center.1 - radius),
+            (self.center.0 + radius, self.center.1 + radius),
+        )
+    }
+}
+
+impl ShapeIter for Circle {
+    type Iterator = CircleIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Circle {
+    type IntoIter = CircleIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        CircleIter::new(self)
+    }
+}
+
+impl ShapeWithBorder for Circle {
+    #[inline]
+    fn get_border_count(&self) -> usize {
+        self.circumference_iter().count()
+    }
+
+    #[inline]
+    fn border_contains(&self, position: (i32, i32)) -> bool {
+        let dx = (position.0 - self.center.0).unsigned_abs() as i64;
+        let dy = (position.1 - self.center.1).unsigned_abs() as i64;
+        let (x, y) = (dx.min(dy), dx.max(dy));
+        x <= y && self.octant_y(x) == y
+    }
+
+    #[inline]
+    fn border_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.circumference_iter())
+    }
+
+    #[inline]
+    fn get_border_positions(&self) -> HashSet<(i32, i32)> {
+        self.get_circumfrence()
+    }
+}
+
+impl From<Circle> for BoxedShape {
+    fn from(value: Circle) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::Circle;
+    use crate::{
+        direction::{Direction, YAxis},
+        grid_shapes::{Line, Shape, ShapeIter, ShapeWithBorder},
+    };
+
+    /// The original `HashSet` based implementation, kept as a reference for the scanline version.
+    fn reference_positions(circle: &Circle) -> HashSet<(i32, i32)> {
+        let center = circle.center();
         let mut discovered = HashSet::new();
-        let mut d = (5 - (self.radius as i32 * 4)) / 4;
+        let mut d = (5 - (circle.radius() as i32 * 4)) / 4;
         let mut x = 0;
-        let mut y = self.radius as i32;
+        let mut y = circle.radius() as i32;
 
-        let mut start;
-        let mut end;
-        let mut line;
         loop {
-            start = (self.center.0 + x, self.center.1 + y);
-            end = (self.center.0 + x, self.center.1 - y);
-            line = Line::new(start, end);
-            for point in line.get_positions() {
-                discovered.insert(point);
+            for (start, end) in [
+                ((center.0 + x, center.1 + y), (center.0 + x, center.1 - y)),
+                ((center.0 - x, center.1 + y), (center.0 - x, center.1 - y)),
+                ((center.0 + y, center.1 + x), (center.0 + y, center.1 - x)),
+                ((center.0 - y, center.1 + x), (center.0 - y, center.1 - x)),
+            ] {
+                discovered.extend(Line::new(start, end));
             }
 
-            start = (self.center.0 - x, self.center.1 + y);
-            end = (self.center.0 - x, self.center.1 - y);
-            line = Line::new(start, end);
-            for point in line.get_positions() {
-                discovered.insert(point);
+            if d < 0 {
+                d += (2 * x) + 1;
+            } else {
+                d += (2 * (x - y)) + 1;
+                y -= 1;
             }
+            x += 1;
 
-            start = (self.center.0 + y, self.center.1 + x);
-            end = (self.center.0 + y, self.center.1 - x);
-            line = Line::new(start, end);
-            for point in line.get_positions() {
-                discovered.insert(point);
+            if x > y {
+                break;
             }
+        }
+        discovered
+    }
 
-            start = (self.center.0 - y, self.center.1 + x);
-            end = (self.center.0 - y, self.center.1 - x);
-            line = Line::new(start, end);
-            for point in line.get_positions() {
-                discovered.insert(point);
-            }
+    /// The original `HashSet` based circumference, kept as a reference for `CircumferenceIter`.
+    fn reference_circumference(circle: &Circle) -> HashSet<(i32, i32)> {
+        let center = circle.center();
+        let mut discovered = HashSet::new();
+        let mut d = (5 - (circle.radius() as i32 * 4)) / 4;
+        let mut x = 0;
+        let mut y = circle.radius() as i32;
 
-            //// Circumfrence
-            // discovered.insert((self.center.0 + x, self.center.1 + y));
-            // discovered.insert((self.center.0 + x, self.center.1 - y));
-            // discovered.insert((self.center.0 - x, self.center.1 + y));
-            // discovered.insert((self.center.0 - x, self.center.1 - y));
-            // discovered.insert((self.center.0 + y, self.center.1 + x));
-            // discovered.insert((self.center.0 + y, self.center.1 - x));
-            // discovered.insert((self.center.0 - y, self.center.1 + x));
-            // discovered.insert((self.center.0 - y, self.center.1 - x));
+        loop {
+            for (mx, my) in [(x, y), (y, x)] {
+                for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+                    discovered.insert((center.0 + sx * mx, center.1 + sy * my));
+                }
+            }
 
             if d < 0 {
                 d += (2 * x) + 1;
@@ -176,33 +352,101 @@ impl Shape for Circle {
         discovered
     }
 
-    #[inline]
-    fn boxed_iter(&self) -> BoxedShapeIter {
-        Box::new(self.into_iter())
+    #[test]
+    fn test_circle_matches_reference() {
+        for radius in 0..=64u32 {
+            for center in [(0, 0), (3, -7), (-12, 5)] {
+                let circle = Circle::new(center, radius);
+                let expected = reference_positions(&circle);
+
+                let points: Vec<(i32, i32)> = circle.iter().collect();
+                let unique: HashSet<(i32, i32)> = points.iter().copied().collect();
+                assert_eq!(
+                    points.len(),
+                    unique.len(),
+                    "duplicate points, radius {radius}"
+                );
+                assert_eq!(unique, expected, "point set, radius {radius}");
+                assert_eq!(circle.get_count() as usize, expected.len());
+
+                let r = radius as i32 + 2;
+                for y in center.1 - r..=center.1 + r {
+                    for x in center.0 - r..=center.0 + r {
+                        assert_eq!(circle.contains((x, y)), expected.contains(&(x, y)));
+                    }
+                }
+            }
+        }
     }
-}
 
-impl ShapeIter for Circle {
-    type Iterator = std::collections::hash_set::IntoIter<(i32, i32)>;
+    #[test]
+    fn test_circle_iter_is_deterministic() {
+        let circle = Circle::new((4, 4), 5u32);
+        let first: Vec<(i32, i32)> = circle.iter().collect();
+        let second: Vec<(i32, i32)> = circle.boxed_iter().collect();
+        assert_eq!(first, second);
+        assert_eq!(first.first().map(|p| p.1), Some(circle.bottom().1));
+        assert_eq!(first.last().map(|p| p.1), Some(circle.top().1));
+        assert!(first
+            .windows(2)
+            .all(|w| (w[0].1, w[0].0) < (w[1].1, w[1].0)));
+    }
 
-    #[inline]
-    fn iter(&self) -> Self::Iterator {
-        self.get_positions().into_iter()
+    #[test]
+    fn test_circle_border() {
+        for radius in 0..=32u32 {
+            let circle = Circle::new((2, -3), radius);
+            let border: Vec<(i32, i32)> = circle.border_iter().collect();
+            let unique: HashSet<(i32, i32)> = border.iter().copied().collect();
+            assert_eq!(
+                border.len(),
+                unique.len(),
+                "duplicate points, radius {radius}"
+            );
+            assert_eq!(unique, reference_circumference(&circle), "radius {radius}");
+            assert_eq!(circle.get_circumfrence(), unique);
+            assert_eq!(circle.get_border_count(), unique.len());
+
+            let r = radius as i32 + 2;
+            for y in -3 - r..=-3 + r {
+                for x in 2 - r..=2 + r {
+                    assert_eq!(circle.border_contains((x, y)), unique.contains(&(x, y)));
+                    if unique.contains(&(x, y)) {
+                        assert!(circle.contains((x, y)));
+                    }
+                }
+            }
+        }
     }
-}
 
-impl IntoIterator for Circle {
-    type IntoIter = std::collections::hash_set::IntoIter<(i32, i32)>;
-    type Item = (i32, i32);
+    #[test]
+    fn test_circle_serde_and_reflect() {
+        use bevy::reflect::{FromReflect, PartialReflect};
 
-    #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        self.get_positions().into_iter()
+        let circle = Circle::new((3, -2), 7u32);
+        let ron = ron::to_string(&circle).unwrap();
+        assert_eq!(ron, "(center:(3,-2),radius:7)");
+        let loaded: Circle = ron::from_str(&ron).unwrap();
+        assert_eq!(loaded, circle);
+        assert_eq!(loaded.get_count(), circle.iter().count() as u32);
+
+        let reflected = Circle::from_reflect(circle.as_partial_reflect()).unwrap();
+        assert_eq!(reflected, circle);
+        assert_eq!(reflected.get_count(), circle.get_count());
     }
-}
 
-impl From<Circle> for BoxedShape {
-    fn from(value: Circle) -> Self {
-        Box::new(value)
+    #[test]
+    fn test_circle_y_axis() {
+        let circle = Circle::new((5, 5), 2u32);
+        assert_eq!(circle.top_in(YAxis::Up), (5, 7));
+        assert_eq!(circle.bottom_in(YAxis::Up), (5, 3));
+        assert_eq!(circle.top_in(YAxis::Down), (5, 3));
+        assert_eq!(circle.bottom_in(YAxis::Down), (5, 7));
+
+        for y_axis in [YAxis::Up, YAxis::Down] {
+            let north = Direction::NORTH.coord_in(y_axis);
+            let top = circle.top_in(y_axis);
+            assert_eq!(top, (5 + north.0 * 2, 5 + north.1 * 2));
+        }
     }
 }
@@ -0,0 +1,298 @@
+use std::iter::Chain;
+
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::ShapeFilterIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+};
+
+/// The points which are in either of two shapes.
+///
+/// Points of `a` are yielded first, followed by the points of `b` not already in `a`.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Union<A, B> {
+    a: A,
+    b: B,
+}
+
+/// The points which are in both of two shapes.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Intersection<A, B> {
+    a: A,
+    b: B,
+}
+
+/// The points of one shape which are not in another shape.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Difference<A, B> {
+    a: A,
+    b: B,
+}
+
+/// The points which are in exactly one of two shapes.
+///
+/// Points of `a` are yielded first, followed by the points of `b`.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Xor<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Shape, B: Shape> Union<A, B> {
+    /// Creates the union of two shapes.
+    pub const fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A: Shape, B: Shape> Intersection<A, B> {
+    /// Creates the intersection of two shapes.
+    pub const fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A: Shape, B: Shape> Difference<A, B> {
+    /// Creates the points of `a` which are not in `b`.
+    pub const fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A: Shape, B: Shape> Xor<A, B> {
+    /// Creates the points which are in only one of two shapes.
+    pub const fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+// Shape
+impl<A: Shape, B: Shape> Shape for Union<A, B> {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.a.get_count() + self.b.get_count() - overlap_count(&self.a, &self.b)
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.a.contains(position) || self.b.contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(
+            self.a
+                .boxed_iter()
+                .chain(ShapeFilterIter::outside(self.b.boxed_iter(), &self.a)),
+        )
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.a.bounds().union(self.b.bounds())
+    }
+}
+
+impl<A: Shape, B: Shape> Shape for Intersection<A, B> {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        overlap_count(&self.a, &self.b)
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.a.contains(position) && self.b.contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(ShapeFilterIter::inside(self.a.boxed_iter(), &self.b))
+    }
+
+    /// NOTE: When the bounds of `a` and `b` do not overlap the shape is empty and the bounds of
+    /// `a` are returned.
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let a = self.a.bounds();
+        a.intersection(self.b.bounds()).unwrap_or(a)
+    }
+}
+
+impl<A: Shape, B: Shape> Shape for Difference<A, B> {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.a.get_count() - overlap_count(&self.a, &self.b)
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.a.contains(position) && !self.b.contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(ShapeFilterIter::outside(self.a.boxed_iter(), &self.b))
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.a.bounds()
+    }
+}
+
+impl<A: Shape, B: Shape> Shape for Xor<A, B> {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.a.get_count() + self.b.get_count() - 2 * overlap_count(&self.a, &self.b)
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.a.contains(position) != self.b.contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(
+            ShapeFilterIter::outside(self.a.boxed_iter(), &self.b)
+                .chain(ShapeFilterIter::outside(self.b.boxed_iter(), &self.a)),
+        )
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Union::new(&self.a, &self.b).bounds()
+    }
+}
+
+// ShapeIter
+impl<A: ShapeIter + Shape + Clone, B: ShapeIter + Shape> ShapeIter for Union<A, B> {
+    type Iterator = Chain<A::Iterator, ShapeFilterIter<B::Iterator, A>>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.a
+            .iter()
+            .chain(ShapeFilterIter::outside(self.b.iter(), self.a.clone()))
+    }
+}
+
+impl<A: ShapeIter + Shape, B: Shape + Clone> ShapeIter for Intersection<A, B> {
+    type Iterator = ShapeFilterIter<A::Iterator, B>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        ShapeFilterIter::inside(self.a.iter(), self.b.clone())
+    }
+}
+
+impl<A: ShapeIter + Shape, B: Shape + Clone> ShapeIter for Difference<A, B> {
+    type Iterator = ShapeFilterIter<A::Iterator, B>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        ShapeFilterIter::outside(self.a.iter(), self.b.clone())
+    }
+}
+
+impl<A: ShapeIter + Shape + Clone, B: ShapeIter + Shape + Clone> ShapeIter for Xor<A, B> {
+    type Iterator = Chain<ShapeFilterIter<A::Iterator, B>, ShapeFilterIter<B::Iterator, A>>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        ShapeFilterIter::outside(self.a.iter(), self.b.clone())
+            .chain(ShapeFilterIter::outside(self.b.iter(), self.a.clone()))
+    }
+}
+
+// BoxedShape
+impl<A: Shape + 'static, B: Shape + 'static> From<Union<A, B>> for BoxedShape {
+    fn from(value: Union<A, B>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<A: Shape + 'static, B: Shape + 'static> From<Intersection<A, B>> for BoxedShape {
+    fn from(value: Intersection<A, B>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<A: Shape + 'static, B: Shape + 'static> From<Difference<A, B>> for BoxedShape {
+    fn from(value: Difference<A, B>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<A: Shape + 'static, B: Shape + 'static> From<Xor<A, B>> for BoxedShape {
+    fn from(value: Xor<A, B>) -> Self {
+        Box::new(value)
+    }
+}
+
+/// The number of points of `a` which are also in `b`
+#[inline]
+fn overlap_count(a: &impl Shape, b: &impl Shape) -> u32 {
+    ShapeFilterIter::inside(a.boxed_iter(), b).count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{Difference, Intersection, Union, Xor};
+    use crate::grid_shapes::{BoxedShape, Circle, Line, Rectangle, Shape, ShapeIter};
+
+    fn assert_shape(shape: &impl Shape, expected: &HashSet<(i32, i32)>) {
+        let points: Vec<(i32, i32)> = shape.boxed_iter().collect();
+        let unique: HashSet<(i32, i32)> = points.iter().copied().collect();
+        assert_eq!(points.len(), unique.len());
+        assert_eq!(&unique, expected);
+        assert_eq!(shape.get_count() as usize, expected.len());
+        assert!(expected.iter().all(|p| shape.contains(*p)));
+        assert!(expected.iter().all(|p| shape.bounds().contains(*p)));
+    }
+
+    #[test]
+    fn test_composite_shapes() {
+        let circle = Circle::new((0, 0), 6u32);
+        let room = Rectangle::new((-2, -2), (9, 3));
+        let a = circle.get_positions();
+        let b = room.get_positions();
+
+        assert_shape(&Union::new(circle, room), &(&a | &b));
+        assert_shape(&Intersection::new(circle, room), &(&a & &b));
+        assert_shape(&Difference::new(circle, room), &(&a - &b));
+        assert_shape(&Xor::new(circle, room), &(&a ^ &b));
+
+        let union = Union::new(circle, room);
+        assert_eq!(
+            union.iter().collect::<Vec<_>>(),
+            union.boxed_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_composite_shapes_nest() {
+        // a ring with a corridor leaving it
+        let ring = Difference::new(Circle::new((0, 0), 5u32), Circle::new((0, 0), 3u32));
+        let corridor = Line::new((0, 0), (12, 0));
+        let shape: BoxedShape = Union::new(ring, corridor).into();
+        let boxed: BoxedShape = Xor::new(shape, Rectangle::new((10, -1), (14, 1))).into();
+
+        let expected = &(&(&Circle::new((0, 0), 5u32).get_positions()
+            - &Circle::new((0, 0), 3u32).get_positions())
+            | &corridor.get_positions())
+            ^ &Rectangle::new((10, -1), (14, 1)).get_positions();
+        assert_shape(&boxed, &expected);
+        assert!(!boxed.contains((1, 1)));
+        assert!(boxed.contains((1, 0)));
+        assert!(!boxed.contains((11, 0)));
+        assert!(boxed.contains((14, 0)));
+
+        let ron = ron::to_string(&ring).unwrap();
+        let deserialized: Difference<Circle, Circle> = ron::from_str(&ron).unwrap();
+        assert_eq!(ring, deserialized);
+    }
+}
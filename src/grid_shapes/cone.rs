@@ -0,0 +1,181 @@
+use std::f32::consts::{PI, TAU};
+
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    direction::Direction,
+    grid_shapes::{
+        iter::FilteredRectIter, BoxedShape, BoxedShapeIter, Circle, Rectangle, Shape, ShapeIter,
+    },
+};
+
+/// A cone, the wedge of a circle around `origin` which is within `spread / 2` of `angle`.
+///
+/// Angles are in radians, counter clockwise from `EAST`.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct Cone {
+    origin: (i32, i32),
+    angle: f32,
+    spread: f32,
+    range: u32,
+}
+
+impl Cone {
+    /// Creates a new cone facing `direction`.
+    ///
+    /// NOTE: A [`Direction`] without a horizontal component faces `EAST`.
+    pub fn new<R: Into<u32>>(
+        origin: (i32, i32),
+        direction: Direction,
+        spread: f32,
+        range: R,
+    ) -> Self {
+        let (x, y) = direction.coord();
+        Self::from_angle(origin, (y as f32).atan2(x as f32), spread, range)
+    }
+
+    /// Creates a new cone facing `angle`.
+    pub fn from_angle<R: Into<u32>>(origin: (i32, i32), angle: f32, spread: f32, range: R) -> Self {
+        Self {
+            origin,
+            angle,
+            spread,
+            range: range.into(),
+        }
+    }
+}
+
+impl Cone {
+    /// Get the origin of the cone
+    #[inline]
+    pub const fn origin(&self) -> (i32, i32) {
+        self.origin
+    }
+
+    /// Get the angle the cone is facing
+    #[inline]
+    pub const fn angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Get the total angle covered by the cone
+    #[inline]
+    pub const fn spread(&self) -> f32 {
+        self.spread
+    }
+
+    /// Get the range of the cone
+    #[inline]
+    pub const fn range(&self) -> u32 {
+        self.range
+    }
+
+    /// Get the circle the cone is cut from
+    #[inline]
+    pub fn as_circle(&self) -> Circle {
+        Circle::new(self.origin, self.range)
+    }
+}
+
+impl Shape for Cone {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.iter().count() as u32
+    }
+
+    fn contains(&self, position: (i32, i32)) -> bool {
+        if position == self.origin {
+            return true;
+        }
+        if !self.as_circle().contains(position) {
+            return false;
+        }
+
+        let dx = (position.0 - self.origin.0) as f32;
+        let dy = (position.1 - self.origin.1) as f32;
+        // difference wrapped into `-PI..=PI`
+        let difference = (dy.atan2(dx) - self.angle + PI).rem_euclid(TAU) - PI;
+        difference.abs() <= self.spread / 2.0 + f32::EPSILON * 8.0
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let range = self.range as i32;
+        Rectangle::new(
+            (self.origin.0 - range, self.origin.1 - range),
+            (self.origin.0 + range, self.origin.1 + range),
+        )
+    }
+}
+
+impl ShapeIter for Cone {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Cone {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<Cone> for BoxedShape {
+    fn from(value: Cone) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+    use super::Cone;
+    use crate::{
+        direction::Direction,
+        grid_shapes::{Shape, ShapeIter},
+    };
+
+    #[test]
+    fn test_cone_count() {
+        // a full turn is the whole circle
+        let cone = Cone::new((0, 0), Direction::NORTH, TAU, 6u32);
+        assert_eq!(cone.get_count(), cone.as_circle().get_count());
+
+        // no spread is a straight line
+        let cone = Cone::new((0, 0), Direction::NORTH, 0.0, 4u32);
+        assert_eq!(
+            cone.iter().collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (0, 2), (0, 3), (0, 4)]
+        );
+        let cone = Cone::new((2, 2), Direction::SOUTH_WEST, 0.0, 3u32);
+        assert_eq!(
+            cone.iter().collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1), (2, 2)]
+        );
+
+        // a half turn is half of the circle plus the dividing line
+        let cone = Cone::new((0, 0), Direction::EAST, PI, 5u32);
+        let circle = cone.as_circle().get_count();
+        assert_eq!(cone.get_count(), (circle - 11) / 2 + 11);
+
+        // opposite quarter turns meet only at the origin
+        let east = Cone::new((0, 0), Direction::EAST, FRAC_PI_2, 8u32);
+        let west = Cone::from_angle((0, 0), PI, FRAC_PI_2, 8u32);
+        assert_eq!(east.get_count(), west.get_count());
+        assert_eq!(east.iter().filter(|p| west.contains(*p)).count(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::FilteredRectIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+};
+
+/// A plus shaped cross, a center point with four arms of `length` points each.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Cross {
+    center: (i32, i32),
+    length: u32,
+}
+
+impl Cross {
+    /// Creates a new cross.
+    pub fn new<L: Into<u32>>(center: (i32, i32), length: L) -> Self {
+        Self {
+            center,
+            length: length.into(),
+        }
+    }
+}
+
+impl Cross {
+    /// Get the center of the cross
+    #[inline]
+    pub const fn center(&self) -> (i32, i32) {
+        self.center
+    }
+
+    /// Get the length of each arm of the cross
+    #[inline]
+    pub const fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+impl Shape for Cross {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        4 * self.length + 1
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        let dx = (position.0 - self.center.0).unsigned_abs();
+        let dy = (position.1 - self.center.1).unsigned_abs();
+        (dx == 0 && dy <= self.length) || (dy == 0 && dx <= self.length)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let length = self.length as i32;
+        Rectangle::new(
+            (self.center.0 - length, self.center.1 - length),
+            (self.center.0 + length, self.center.1 + length),
+        )
+    }
+}
+
+impl ShapeIter for Cross {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Cross {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<Cross> for BoxedShape {
+    fn from(value: Cross) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cross;
+    use crate::grid_shapes::{Shape, ShapeIter};
+
+    #[test]
+    fn test_cross_count() {
+        assert_eq!(Cross::new((0, 0), 0u32).iter().count(), 1);
+        assert_eq!(Cross::new((0, 0), 1u32).iter().count(), 5);
+        assert_eq!(Cross::new((0, 0), 3u32).iter().count(), 13);
+        for length in 0..16u32 {
+            let cross = Cross::new((-7, 2), length);
+            assert_eq!(cross.iter().count() as u32, cross.get_count());
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::CuboidIter, BoxedShape3, BoxedShape3Iter, Rectangle, Shape3, Shape3Iter,
+};
+
+/// An axis aligned 3D box.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Cuboid {
+    /// The minimum coordinates of the cuboid.
+    pub min: (i32, i32, i32),
+    /// The maximum coordinates of the cuboid.
+    pub max: (i32, i32, i32),
+}
+
+impl Default for Cuboid {
+    fn default() -> Self {
+        Self::new_with_size((0, 0, 0), (0, 0, 0))
+    }
+}
+
+impl Cuboid {
+    /// Creates a new cuboid.
+    #[inline]
+    pub fn new(min: (i32, i32, i32), max: (i32, i32, i32)) -> Self {
+        Self {
+            min: (min.0.min(max.0), min.1.min(max.1), min.2.min(max.2)),
+            max: (min.0.max(max.0), min.1.max(max.1), min.2.max(max.2)),
+        }
+    }
+
+    /// Creates a new cuboid with the given size.
+    #[inline]
+    pub fn new_with_size(min: (i32, i32, i32), dimensions: (u32, u32, u32)) -> Self {
+        let max = (
+            min.0 + dimensions.0 as i32,
+            min.1 + dimensions.1 as i32,
+            min.2 + dimensions.2 as i32,
+        );
+        Self::new(min, max)
+    }
+
+    /// Creates a new cuboid from a rectangle covering the layers `bottom..=top`.
+    #[inline]
+    pub fn from_rectangle(rectangle: Rectangle, bottom: i32, top: i32) -> Self {
+        Self::new(
+            (rectangle.min.0, rectangle.min.1, bottom),
+            (rectangle.max.0, rectangle.max.1, top),
+        )
+    }
+}
+
+impl Cuboid {
+    /// Get the width of the cuboid.
+    #[inline]
+    pub const fn width(&self) -> i32 {
+        self.max.0 - self.min.0
+    }
+
+    /// Get the height of the cuboid.
+    #[inline]
+    pub const fn height(&self) -> i32 {
+        self.max.1 - self.min.1
+    }
+
+    /// Get the depth of the cuboid.
+    #[inline]
+    pub const fn depth(&self) -> i32 {
+        self.max.2 - self.min.2
+    }
+
+    /// Get the minimum point of the cuboid.
+    #[inline]
+    pub const fn min(&self) -> (i32, i32, i32) {
+        self.min
+    }
+
+    /// Get the maximum point of the cuboid.
+    #[inline]
+    pub const fn max(&self) -> (i32, i32, i32) {
+        self.max
+    }
+
+    /// Get the center of the cuboid.
+    #[inline]
+    pub const fn center(&self) -> (i32, i32, i32) {
+        (
+            (self.min.0 + self.max.0) / 2,
+            (self.min.1 + self.max.1) / 2,
+            (self.min.2 + self.max.2) / 2,
+        )
+    }
+
+    /// Get the footprint of the cuboid.
+    #[inline]
+    pub fn as_rectangle(&self) -> Rectangle {
+        Rectangle::new((self.min.0, self.min.1), (self.max.0, self.max.1))
+    }
+
+    /// Check if this cuboid intersects another cuboid.
+    #[inline]
+    pub const fn intersects(&self, other: Self) -> bool {
+        self.min.0 <= other.max.0
+            && self.max.0 >= other.min.0
+            && self.min.1 <= other.max.1
+            && self.max.1 >= other.min.1
+            && self.min.2 <= other.max.2
+            && self.max.2 >= other.min.2
+    }
+}
+
+impl Shape3 for Cuboid {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        ((self.width() + 1) * (self.height() + 1) * (self.depth() + 1)) as u32
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32, i32)) -> bool {
+        position.0 >= self.min.0
+            && position.0 <= self.max.0
+            && position.1 >= self.min.1
+            && position.1 <= self.max.1
+            && position.2 >= self.min.2
+            && position.2 <= self.max.2
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShape3Iter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Cuboid {
+        *self
+    }
+}
+
+impl Shape3Iter for Cuboid {
+    type Iterator = CuboidIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Cuboid {
+    type IntoIter = CuboidIter;
+    type Item = (i32, i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        CuboidIter::new(self.min, self.max)
+    }
+}
+
+impl From<Cuboid> for BoxedShape3 {
+    fn from(value: Cuboid) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cuboid;
+    use crate::grid_shapes::{Rectangle, Shape, Shape3, Shape3Iter};
+
+    #[test]
+    fn test_cuboid() {
+        let cuboid = Cuboid::new((2, -1, 4), (-1, 3, 2));
+        assert_eq!(cuboid.min(), (-1, -1, 2));
+        assert_eq!(cuboid.max(), (2, 3, 4));
+
+        let points: Vec<(i32, i32, i32)> = cuboid.iter().collect();
+        assert_eq!(points.len() as u32, cuboid.get_count());
+        assert_eq!(cuboid.get_count(), 4 * 5 * 3);
+        assert!(points.iter().all(|&p| cuboid.contains(p)));
+        assert!(!cuboid.contains((0, 0, 5)));
+
+        // each layer is a rectangle, bottom first
+        let layer = cuboid.as_rectangle();
+        for (z, chunk) in points.chunks(layer.get_count() as usize).enumerate() {
+            let expected: Vec<(i32, i32, i32)> = layer
+                .into_iter()
+                .map(|(x, y)| (x, y, 2 + z as i32))
+                .collect();
+            assert_eq!(chunk, expected.as_slice());
+        }
+        assert_eq!(
+            Cuboid::from_rectangle(Rectangle::new((-1, -1), (2, 3)), 2, 4),
+            cuboid
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::FilteredCuboidIter, BoxedShape3, BoxedShape3Iter, Circle, Cuboid, Shape, Shape3,
+    Shape3Iter,
+};
+
+/// A vertical cylinder, a [`Circle`] on every layer from `base` up `height` layers.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Cylinder {
+    base: (i32, i32, i32),
+    radius: u32,
+    height: u32,
+}
+
+impl Cylinder {
+    /// Creates a new cylinder standing on the center of its bottom layer, `base`.
+    pub fn new<R: Into<u32>>(base: (i32, i32, i32), radius: R, height: u32) -> Self {
+        Self {
+            base,
+            radius: radius.into(),
+            height,
+        }
+    }
+}
+
+impl Cylinder {
+    /// Get the center of the bottom layer
+    #[inline]
+    pub const fn base(&self) -> (i32, i32, i32) {
+        self.base
+    }
+
+    /// Get the radius of the cylinder
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// Get the number of layers above the base
+    #[inline]
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the circle on each layer
+    #[inline]
+    pub fn as_circle(&self) -> Circle {
+        Circle::new((self.base.0, self.base.1), self.radius)
+    }
+}
+
+impl Shape3 for Cylinder {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.as_circle().get_count() * (self.height + 1)
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32, i32)) -> bool {
+        position.2 >= self.base.2
+            && position.2 <= self.base.2 + self.height as i32
+            && self.as_circle().contains((position.0, position.1))
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShape3Iter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Cuboid {
+        Cuboid::from_rectangle(
+            self.as_circle().bounds(),
+            self.base.2,
+            self.base.2 + self.height as i32,
+        )
+    }
+}
+
+impl Shape3Iter for Cylinder {
+    type Iterator = FilteredCuboidIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Cylinder {
+    type IntoIter = FilteredCuboidIter<Self>;
+    type Item = (i32, i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredCuboidIter::new(self)
+    }
+}
+
+impl From<Cylinder> for BoxedShape3 {
+    fn from(value: Cylinder) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cylinder;
+    use crate::grid_shapes::{Shape, Shape3, Shape3Iter};
+
+    #[test]
+    fn test_cylinder() {
+        let cylinder = Cylinder::new((1, 2, -1), 3u32, 2);
+        let circle = cylinder.as_circle();
+        let points: Vec<(i32, i32, i32)> = cylinder.iter().collect();
+        assert_eq!(points.len() as u32, cylinder.get_count());
+        assert_eq!(cylinder.get_count(), circle.get_count() * 3);
+        for z in -1..=1 {
+            let layer: Vec<(i32, i32)> = points
+                .iter()
+                .filter(|p| p.2 == z)
+                .map(|&(x, y, _)| (x, y))
+                .collect();
+            assert_eq!(
+                layer.into_iter().collect::<std::collections::HashSet<_>>(),
+                circle.get_positions()
+            );
+        }
+        assert!(!cylinder.contains((1, 2, 2)));
+        assert!(!cylinder.contains((1, 2, -2)));
+    }
+}
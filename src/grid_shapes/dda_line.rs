@@ -0,0 +1,117 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::DdaLineIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter, ShapeWithBorder,
+};
+
+/// A line drawn with a digital differential analyzer.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DdaLine {
+    end: (i32, i32),
+    start: (i32, i32),
+}
+
+impl DdaLine {
+    /// Creates a new DDA line.
+    #[inline(always)]
+    pub const fn new(start: (i32, i32), end: (i32, i32)) -> Self {
+        Self { start, end }
+    }
+}
+
+impl Shape for DdaLine {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        (self.end.0 - self.start.0)
+            .abs()
+            .max((self.end.1 - self.start.1).abs()) as u32
+            + 1
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        let iter = self.iter();
+        iter.step_of(position)
+            .is_some_and(|step| iter.point_at(step) == position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.start, self.end)
+    }
+}
+
+/// A line is all border.
+impl ShapeWithBorder for DdaLine {
+    #[inline]
+    fn get_border_count(&self) -> usize {
+        self.get_count() as usize
+    }
+
+    #[inline]
+    fn border_contains(&self, position: (i32, i32)) -> bool {
+        self.contains(position)
+    }
+
+    #[inline]
+    fn border_iter(&self) -> BoxedShapeIter<'_> {
+        self.boxed_iter()
+    }
+}
+
+impl ShapeIter for DdaLine {
+    type Iterator = DdaLineIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for DdaLine {
+    type IntoIter = DdaLineIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        DdaLineIter::new(self.start, self.end)
+    }
+}
+
+impl From<DdaLine> for BoxedShape {
+    fn from(value: DdaLine) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DdaLine;
+    use crate::grid_shapes::{Rectangle, Shape, ShapeIter};
+
+    #[test]
+    fn test_dda_line() {
+        for end in Rectangle::new((-9, -9), (9, 9)) {
+            let line = DdaLine::new((0, 0), end);
+            let points: Vec<(i32, i32)> = line.iter().collect();
+            assert_eq!(points.len() as u32, line.get_count());
+            assert_eq!((points[0], points[points.len() - 1]), ((0, 0), end));
+
+            // every step moves to a neighbouring point
+            for pair in points.windows(2) {
+                let step = (pair[1].0 - pair[0].0, pair[1].1 - pair[0].1);
+                assert_eq!(step.0.abs().max(step.1.abs()), 1, "{end:?}");
+            }
+
+            for position in Rectangle::new((-11, -11), (11, 11)) {
+                assert_eq!(line.contains(position), points.contains(&position));
+            }
+        }
+    }
+}
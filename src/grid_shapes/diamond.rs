@@ -0,0 +1,106 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::FilteredRectIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+};
+
+/// A diamond, every point within `radius` steps of the center using Manhattan distance.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Diamond {
+    center: (i32, i32),
+    radius: u32,
+}
+
+impl Diamond {
+    /// Creates a new diamond.
+    pub fn new<R: Into<u32>>(center: (i32, i32), radius: R) -> Self {
+        Self {
+            center,
+            radius: radius.into(),
+        }
+    }
+}
+
+impl Diamond {
+    /// Get the center of the diamond
+    #[inline]
+    pub const fn center(&self) -> (i32, i32) {
+        self.center
+    }
+
+    /// Get the radius of the diamond
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+}
+
+impl Shape for Diamond {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        2 * self.radius * (self.radius + 1) + 1
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        (position.0 - self.center.0).unsigned_abs() + (position.1 - self.center.1).unsigned_abs()
+            <= self.radius
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let radius = self.radius as i32;
+        Rectangle::new(
+            (self.center.0 - radius, self.center.1 - radius),
+            (self.center.0 + radius, self.center.1 + radius),
+        )
+    }
+}
+
+impl ShapeIter for Diamond {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Diamond {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<Diamond> for BoxedShape {
+    fn from(value: Diamond) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Diamond;
+    use crate::grid_shapes::{Shape, ShapeIter};
+
+    #[test]
+    fn test_diamond_count() {
+        assert_eq!(Diamond::new((0, 0), 0u32).iter().count(), 1);
+        assert_eq!(Diamond::new((0, 0), 1u32).iter().count(), 5);
+        assert_eq!(Diamond::new((0, 0), 2u32).iter().count(), 13);
+        for radius in 0..16u32 {
+            let diamond = Diamond::new((3, -1), radius);
+            assert_eq!(diamond.iter().count() as u32, diamond.get_count());
+        }
+    }
+}
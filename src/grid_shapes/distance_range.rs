@@ -0,0 +1,173 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    distance::Distance,
+    grid_shapes::{
+        iter::FilteredRectIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+    },
+};
+
+/// Every point within a radius of a center by a [`Distance`]
+///
+/// `Chebyshev` gives a square, `Manhattan` a diamond, `Pythagoras` a disc and `Hex` a hexagon
+/// of axial coordinates.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct DistanceRange {
+    center: (i32, i32),
+    radius: u32,
+    distance: Distance,
+}
+
+impl DistanceRange {
+    /// Creates a new range.
+    #[inline(always)]
+    pub const fn new(center: (i32, i32), radius: u32, distance: Distance) -> Self {
+        Self {
+            center,
+            radius,
+            distance,
+        }
+    }
+}
+
+impl DistanceRange {
+    /// Get the center of the range
+    #[inline]
+    pub const fn center(&self) -> (i32, i32) {
+        self.center
+    }
+
+    /// Get the radius of the range
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// Get the [`Distance`] of the range
+    #[inline]
+    pub const fn distance(&self) -> Distance {
+        self.distance
+    }
+}
+
+impl Shape for DistanceRange {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.iter().count() as u32
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.bounds().contains(position)
+            && self
+                .distance
+                .within_radius(self.center, position, self.radius)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.center, self.center).expand(self.distance.reach(self.radius))
+    }
+}
+
+impl ShapeIter for DistanceRange {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for DistanceRange {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<DistanceRange> for BoxedShape {
+    fn from(value: DistanceRange) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DistanceRange;
+    use crate::{
+        distance::Distance,
+        grid_shapes::{Diamond, Rectangle, Shape},
+        hex::HexRange,
+    };
+
+    #[test]
+    fn test_distance_range() {
+        for radius in 0..6 {
+            let square = DistanceRange::new((2, -1), radius, Distance::Chebyshev);
+            assert_eq!(
+                square.get_positions(),
+                Rectangle::new((2, -1), (2, -1))
+                    .expand(radius)
+                    .get_positions()
+            );
+            assert_eq!(square.get_count(), (2 * radius + 1).pow(2));
+
+            let diamond = DistanceRange::new((2, -1), radius, Distance::Manhattan);
+            assert_eq!(
+                diamond.get_positions(),
+                Diamond::new((2, -1), radius).get_positions()
+            );
+
+            let hexagon = DistanceRange::new((0, 0), radius, Distance::Hex);
+            assert_eq!(
+                hexagon.get_positions(),
+                HexRange::new((0, 0).into(), radius).get_positions()
+            );
+
+            let disc = DistanceRange::new((0, 0), radius, Distance::Pythagoras);
+            let squared = DistanceRange::new((0, 0), radius, Distance::PythagorasSquared);
+            assert_eq!(disc.get_positions(), squared.get_positions());
+            let r = radius as i32;
+            for (x, y) in Rectangle::new((-r - 1, -r - 1), (r + 1, r + 1)) {
+                assert_eq!(disc.contains((x, y)), x * x + y * y <= r * r);
+            }
+        }
+    }
+
+    #[test]
+    fn test_distance_range_bounds() {
+        // cheap steps reach further than the radius
+        let range = DistanceRange::new((0, 0), 3, Distance::DiagonalWithCosts(0.5, 1.0));
+        assert_eq!(range.bounds(), Rectangle::new((-6, -6), (6, 6)));
+        assert!(range.contains((6, 0)));
+        assert!(range.contains((3, 3)));
+        assert!(!range.contains((4, 4)));
+
+        for distance in [Distance::Octile, Distance::DiagonalWithCosts(1.0, 0.5)] {
+            let range = DistanceRange::new((0, 0), 4, distance);
+            let bounds = range.bounds();
+            for position in bounds.expand(2) {
+                if range.contains(position) {
+                    assert!(bounds.contains(position), "{distance:?} {position:?}");
+                }
+            }
+        }
+
+        // free steps are clipped to a square of the radius
+        let range = DistanceRange::new((0, 0), 3, Distance::DiagonalWithCosts(0.0, 1.0));
+        assert_eq!(range.bounds(), Rectangle::new((-3, -3), (3, 3)));
+        assert!(range.contains((3, 0)));
+        assert!(!range.contains((100, 0)));
+        assert_eq!(range.get_count(), 49);
+    }
+}
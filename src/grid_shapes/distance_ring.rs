@@ -0,0 +1,173 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    distance::Distance,
+    grid_shapes::{
+        iter::FilteredRectIter, BoxedShape, BoxedShapeIter, DistanceRange, Rectangle, Shape,
+        ShapeIter,
+    },
+};
+
+/// Every point exactly a radius from a center by a [`Distance`]
+///
+/// The points within `radius` which are not within `radius - 1`, `Chebyshev` gives the edge of
+/// a square and `Manhattan` the edge of a diamond.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct DistanceRing {
+    center: (i32, i32),
+    radius: u32,
+    distance: Distance,
+}
+
+impl DistanceRing {
+    /// Creates a new ring.
+    #[inline(always)]
+    pub const fn new(center: (i32, i32), radius: u32, distance: Distance) -> Self {
+        Self {
+            center,
+            radius,
+            distance,
+        }
+    }
+}
+
+impl DistanceRing {
+    /// Get the center of the ring
+    #[inline]
+    pub const fn center(&self) -> (i32, i32) {
+        self.center
+    }
+
+    /// Get the radius of the ring
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// Get the [`Distance`] of the ring
+    #[inline]
+    pub const fn distance(&self) -> Distance {
+        self.distance
+    }
+
+    /// Get the range covered by the ring and everything inside it
+    #[inline]
+    pub const fn as_range(&self) -> DistanceRange {
+        DistanceRange::new(self.center, self.radius, self.distance)
+    }
+}
+
+impl Shape for DistanceRing {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.iter().count() as u32
+    }
+
+    fn contains(&self, position: (i32, i32)) -> bool {
+        let within = |radius| self.distance.within_radius(self.center, position, radius);
+        self.bounds().contains(position)
+            && within(self.radius)
+            && (self.radius == 0 || !within(self.radius - 1))
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.as_range().bounds()
+    }
+}
+
+impl ShapeIter for DistanceRing {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for DistanceRing {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<DistanceRing> for BoxedShape {
+    fn from(value: DistanceRing) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DistanceRing;
+    use crate::{
+        distance::Distance,
+        grid_shapes::{Rectangle, Shape, ShapeIter, ShapeWithBorder},
+        hex::HexRing,
+    };
+
+    #[test]
+    fn test_distance_ring() {
+        assert_eq!(
+            DistanceRing::new((4, 4), 0, Distance::Manhattan)
+                .iter()
+                .collect::<Vec<_>>(),
+            [(4, 4)]
+        );
+
+        for radius in 1..6 {
+            let square = DistanceRing::new((1, 1), radius, Distance::Chebyshev);
+            assert_eq!(
+                square.get_positions(),
+                Rectangle::new((1, 1), (1, 1))
+                    .expand(radius)
+                    .get_border_positions()
+            );
+            assert_eq!(square.get_count(), 8 * radius);
+
+            let diamond = DistanceRing::new((1, 1), radius, Distance::Manhattan);
+            assert_eq!(diamond.get_count(), 4 * radius);
+
+            let hexagon = DistanceRing::new((0, 0), radius, Distance::Hex);
+            assert_eq!(
+                hexagon.get_positions(),
+                HexRing::new((0, 0).into(), radius).get_positions()
+            );
+
+            // rings stack up into the range
+            for distance in [Distance::Pythagoras, Distance::Octile] {
+                let ring = DistanceRing::new((0, 0), radius, distance);
+                let mut filled = ring.get_positions();
+                for inner in 0..radius {
+                    let inner = DistanceRing::new((0, 0), inner, distance).get_positions();
+                    assert!(inner.is_disjoint(&filled));
+                    filled.extend(inner);
+                }
+                assert_eq!(filled, ring.as_range().get_positions());
+            }
+        }
+    }
+
+    #[test]
+    fn test_distance_ring_bounds() {
+        // free steps are clipped to a square of the radius
+        let ring = DistanceRing::new((0, 0), 2, Distance::DiagonalWithCosts(1.0, -1.0));
+        assert!(!ring.contains((100, 0)));
+        for position in ring.bounds().expand(4) {
+            assert_eq!(
+                ring.contains(position),
+                ring.get_positions().contains(&position)
+            );
+        }
+    }
+}
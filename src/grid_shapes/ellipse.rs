@@ -0,0 +1,129 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::FilteredRectIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+};
+
+/// An axis-aligned ellipse
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Ellipse {
+    center: (i32, i32),
+    radius: (u32, u32),
+}
+
+impl Ellipse {
+    /// Creates a new ellipse with a `(horizontal, vertical)` radius.
+    pub const fn new(center: (i32, i32), radius: (u32, u32)) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl Ellipse {
+    /// Get the center of the ellipse
+    #[inline]
+    pub const fn center(&self) -> (i32, i32) {
+        self.center
+    }
+
+    /// Get the `(horizontal, vertical)` radius of the ellipse
+    #[inline]
+    pub const fn radius(&self) -> (u32, u32) {
+        self.radius
+    }
+
+    /// Get the distance from the center to the edge of the scanline `offset` rows away from the
+    /// center, or `None` if the scanline is outside of the ellipse.
+    fn half_width(&self, offset: i32) -> Option<i32> {
+        let offset = offset.unsigned_abs() as u64;
+        let (rx, ry) = (self.radius.0 as u64, self.radius.1 as u64);
+        if offset > ry {
+            None
+        } else if ry == 0 {
+            Some(rx as i32)
+        } else {
+            // largest `x` where `x²/rx² + offset²/ry² <= 1`
+            Some((rx * rx * (ry * ry - offset * offset) / (ry * ry)).isqrt() as i32)
+        }
+    }
+}
+
+impl Shape for Ellipse {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        let ry = self.radius.1 as i32;
+        (-ry..=ry)
+            .filter_map(|offset| self.half_width(offset))
+            .map(|half_width| (half_width * 2 + 1) as u32)
+            .sum()
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        let dx = position.0 - self.center.0;
+        let dy = position.1 - self.center.1;
+        self.half_width(dy)
+            .is_some_and(|half_width| dx.abs() <= half_width)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let radius = (self.radius.0 as i32, self.radius.1 as i32);
+        Rectangle::new(
+            (self.center.0 - radius.0, self.center.1 - radius.1),
+            (self.center.0 + radius.0, self.center.1 + radius.1),
+        )
+    }
+}
+
+impl ShapeIter for Ellipse {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Ellipse {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<Ellipse> for BoxedShape {
+    fn from(value: Ellipse) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ellipse;
+    use crate::grid_shapes::{Shape, ShapeIter};
+
+    #[test]
+    fn test_ellipse_count() {
+        assert_eq!(Ellipse::new((0, 0), (0, 0)).iter().count(), 1);
+        assert_eq!(Ellipse::new((0, 0), (3, 0)).iter().count(), 7);
+        assert_eq!(Ellipse::new((0, 0), (0, 2)).iter().count(), 5);
+        assert_eq!(Ellipse::new((0, 0), (2, 1)).iter().count(), 7);
+        for rx in 0..12u32 {
+            for ry in 0..12u32 {
+                let ellipse = Ellipse::new((1, -4), (rx, ry));
+                assert_eq!(ellipse.iter().count() as u32, ellipse.get_count());
+                assert!(ellipse.contains((1 - rx as i32, -4)));
+                assert!(ellipse.contains((1, -4 + ry as i32)));
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::grid_shapes::Circle;
+
+/// An iterator over the points of a filled circle.
+///
+/// Points are yielded one scanline (row) at a time from the bottom of the circle to the top,
+/// each scanline from left to right.
+#[derive(Debug, Clone)]
+pub struct CircleIter {
+    circle: Circle,
+
+    /// The current offset from the center of the circle.
+    offset: (i32, i32),
+    /// The last `x` offset of the current scanline.
+    row_end: i32,
+}
+
+impl CircleIter {
+    /// Creates a new circle iterator.
+    pub fn new(circle: Circle) -> Self {
+        let radius = circle.radius() as i32;
+        let mut iter = Self {
+            circle,
+            offset: (0, -radius),
+            row_end: 0,
+        };
+        iter.start_row();
+        iter
+    }
+
+    /// Moves `offset` to the first point of the current scanline.
+    #[inline]
+    fn start_row(&mut self) {
+        let half_width = self.circle.half_width(self.offset.1).unwrap_or(-1);
+        self.offset.0 = -half_width;
+        self.row_end = half_width;
+    }
+}
+
+impl Iterator for CircleIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset.1 > self.circle.radius() as i32 {
+            return None;
+        }
+
+        let center = self.circle.center();
+        let p = (center.0 + self.offset.0, center.1 + self.offset.1);
+
+        self.offset.0 += 1;
+        if self.offset.0 > self.row_end {
+            self.offset.1 += 1;
+            self.start_row();
+        }
+
+        Some(p)
+    }
+}
+
+impl From<Circle> for CircleIter {
+    fn from(circle: Circle) -> Self {
+        circle.into_iter()
+    }
+}
@@ -0,0 +1,90 @@
+use crate::grid_shapes::Circle;
+
+/// An iterator over the circumference of a circle.
+///
+/// Walks the first octant with the midpoint algorithm and yields each distinct mirrored point
+/// of every step.
+#[derive(Debug, Clone)]
+pub struct CircumferenceIter {
+    center: (i32, i32),
+    offset: (i32, i32),
+    d: i32,
+    finished: bool,
+
+    /// Mirrored points of the current step not yet yielded.
+    pending: [(i32, i32); 8],
+    pending_len: usize,
+}
+
+impl CircumferenceIter {
+    /// Creates a new circumference iterator.
+    pub fn new(circle: Circle) -> Self {
+        let radius = circle.radius() as i32;
+        Self {
+            center: circle.center(),
+            offset: (0, radius),
+            d: (5 - (radius * 4)) / 4,
+            finished: false,
+            pending: [(0, 0); 8],
+            pending_len: 0,
+        }
+    }
+
+    /// Fills `pending` with the distinct mirrors of the current step, then advances the step.
+    fn step(&mut self) {
+        let (x, y) = self.offset;
+        let mirrors = [
+            (x, y),
+            (x, -y),
+            (-x, y),
+            (-x, -y),
+            (y, x),
+            (y, -x),
+            (-y, x),
+            (-y, -x),
+        ];
+
+        // filled in reverse so `next` can pop them off in order
+        self.pending_len = 0;
+        for (index, &(mx, my)) in mirrors.iter().enumerate().rev() {
+            if mirrors[..index].contains(&(mx, my)) {
+                continue;
+            }
+            self.pending[self.pending_len] = (self.center.0 + mx, self.center.1 + my);
+            self.pending_len += 1;
+        }
+
+        if self.d < 0 {
+            self.d += (2 * x) + 1;
+        } else {
+            self.d += (2 * (x - y)) + 1;
+            self.offset.1 -= 1;
+        }
+        self.offset.0 += 1;
+
+        if self.offset.0 > self.offset.1 {
+            self.finished = true;
+        }
+    }
+}
+
+impl Iterator for CircumferenceIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending_len == 0 {
+            if self.finished {
+                return None;
+            }
+            self.step();
+        }
+        self.pending_len -= 1;
+        Some(self.pending[self.pending_len])
+    }
+}
+
+impl From<Circle> for CircumferenceIter {
+    fn from(circle: Circle) -> Self {
+        Self::new(circle)
+    }
+}
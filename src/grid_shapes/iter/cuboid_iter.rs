@@ -0,0 +1,77 @@
+use crate::grid_shapes::{Cuboid, Shape3};
+
+/// An iterator over a cuboid.
+///
+/// Points are yielded along `X`, then `Y`, then `Z`, so each layer is in the same row-major
+/// order as [`RectIter`](super::RectIter) and layers go from the bottom up.
+#[derive(Debug, Clone)]
+pub struct CuboidIter {
+    offset: (i32, i32, i32),
+    max_offset: (i32, i32, i32),
+
+    /// The minimum corner point of the cuboid.
+    min: (i32, i32, i32),
+}
+
+impl CuboidIter {
+    /// Creates a new cuboid iterator.
+    pub fn new(min: (i32, i32, i32), max: (i32, i32, i32)) -> Self {
+        let size = (max.0 - min.0, max.1 - min.1, max.2 - min.2);
+        Self {
+            min,
+            max_offset: size,
+            offset: (0, 0, 0),
+        }
+    }
+}
+
+impl Iterator for CuboidIter {
+    type Item = (i32, i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset.2 > self.max_offset.2 {
+            return None;
+        }
+        let p = self.offset;
+        self.offset.0 += 1;
+        if self.offset.0 > self.max_offset.0 {
+            self.offset.0 = 0;
+            self.offset.1 += 1;
+            if self.offset.1 > self.max_offset.1 {
+                self.offset.1 = 0;
+                self.offset.2 += 1;
+            }
+        }
+        Some((self.min.0 + p.0, self.min.1 + p.1, self.min.2 + p.2))
+    }
+}
+
+/// An iterator over the points of a 3d shape's bounds which are contained by the shape.
+///
+/// The points are yielded in the same order as [`CuboidIter`].
+#[derive(Debug, Clone)]
+pub struct FilteredCuboidIter<S> {
+    cuboid_iter: CuboidIter,
+    shape: S,
+}
+
+impl<S: Shape3> FilteredCuboidIter<S> {
+    /// Creates a new iterator over the points of `shape`.
+    pub fn new(shape: S) -> Self {
+        let bounds: Cuboid = shape.bounds();
+        Self {
+            cuboid_iter: CuboidIter::new(bounds.min, bounds.max),
+            shape,
+        }
+    }
+}
+
+impl<S: Shape3> Iterator for FilteredCuboidIter<S> {
+    type Item = (i32, i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cuboid_iter
+            .by_ref()
+            .find(|&position| self.shape.contains(position))
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+
+use crate::grid_shapes::iter::SymmetricLineIter;
+
+/// An iterator over the connected path of a rasterized curve.
+///
+/// The curve is sampled up front, gaps between samples are filled with
+/// [`SymmetricLineIter`] so every point is a neighbour of the point before it. A curve which
+/// folds back or crosses itself yields the points it revisits again.
+#[derive(Debug, Clone)]
+pub struct CurvePathIter {
+    points: std::vec::IntoIter<(i32, i32)>,
+}
+
+impl CurvePathIter {
+    /// Creates a new iterator over the path along `samples`.
+    pub fn new(samples: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        let mut points = Vec::new();
+        walk_samples(samples, |start, end| match start {
+            None => points.push(end),
+            Some(start) => points.extend(SymmetricLineIter::new(start, end).skip(1)),
+        });
+
+        Self {
+            points: points.into_iter(),
+        }
+    }
+
+    /// Get the points which have not been yielded yet.
+    #[inline]
+    pub fn as_slice(&self) -> &[(i32, i32)] {
+        self.points.as_slice()
+    }
+}
+
+impl Iterator for CurvePathIter {
+    type Item = (i32, i32);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.points.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.points.size_hint()
+    }
+}
+
+impl ExactSizeIterator for CurvePathIter {}
+
+/// An iterator over the points of a rasterized curve.
+///
+/// Yields every point of the [`CurvePathIter`] once, in the order the path first visits them.
+#[derive(Debug, Clone)]
+pub struct CurveIter {
+    points: std::vec::IntoIter<(i32, i32)>,
+}
+
+impl CurveIter {
+    /// Creates a new iterator over the points along `samples`.
+    pub fn new(samples: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        Self::from(CurvePathIter::new(samples))
+    }
+
+    /// Get the points which have not been yielded yet.
+    #[inline]
+    pub fn as_slice(&self) -> &[(i32, i32)] {
+        self.points.as_slice()
+    }
+}
+
+impl From<CurvePathIter> for CurveIter {
+    fn from(path: CurvePathIter) -> Self {
+        let mut visited = HashSet::with_capacity(path.len());
+        let points: Vec<_> = path.filter(|&position| visited.insert(position)).collect();
+        Self {
+            points: points.into_iter(),
+        }
+    }
+}
+
+impl Iterator for CurveIter {
+    type Item = (i32, i32);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.points.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.points.size_hint()
+    }
+}
+
+impl ExactSizeIterator for CurveIter {}
+
+/// The number of samples needed along a curve of about `length` so no two samples are more
+/// than half a point apart.
+#[inline]
+pub(crate) fn curve_samples(length: f32) -> u32 {
+    (length * 2.0).ceil().max(1.0) as u32
+}
+
+/// Checks if the path along `samples` passes through `position`.
+///
+/// Only the segments whose bounds hold `position` are rasterized, and nothing is allocated.
+pub(crate) fn curve_contains(
+    samples: impl IntoIterator<Item = (f32, f32)>,
+    position: (i32, i32),
+) -> bool {
+    let mut found = false;
+    walk_samples(samples, |start, end| {
+        found = found
+            || match start {
+                None => end == position,
+                Some(start) => {
+                    (start.0.min(end.0)..=start.0.max(end.0)).contains(&position.0)
+                        && (start.1.min(end.1)..=start.1.max(end.1)).contains(&position.1)
+                        && SymmetricLineIter::new(start, end).any(|point| point == position)
+                }
+            };
+    });
+    found
+}
+
+/// Rounds `samples` to points and calls `segment` with the first point, then with every
+/// pair of different points in a row.
+fn walk_samples(
+    samples: impl IntoIterator<Item = (f32, f32)>,
+    mut segment: impl FnMut(Option<(i32, i32)>, (i32, i32)),
+) {
+    let mut previous = None;
+    for (x, y) in samples {
+        let position = (x.round() as i32, y.round() as i32);
+        if previous != Some(position) {
+            segment(previous, position);
+        }
+        previous = Some(position);
+    }
+}
@@ -0,0 +1,31 @@
+use crate::grid_shapes::{iter::RectIter, Shape};
+
+/// An iterator over the points of a shape's bounds which are contained by the shape.
+///
+/// Used by shapes without a cheaper way to walk their points, the points are yielded in the
+/// same row-major order as [`RectIter`].
+#[derive(Debug, Clone)]
+pub struct FilteredRectIter<S> {
+    rect_iter: RectIter,
+    shape: S,
+}
+
+impl<S: Shape> FilteredRectIter<S> {
+    /// Creates a new iterator over the points of `shape`.
+    pub fn new(shape: S) -> Self {
+        Self {
+            rect_iter: shape.bounds().into_iter(),
+            shape,
+        }
+    }
+}
+
+impl<S: Shape> Iterator for FilteredRectIter<S> {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rect_iter
+            .by_ref()
+            .find(|&position| self.shape.contains(position))
+    }
+}
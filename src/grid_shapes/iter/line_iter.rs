@@ -111,3 +111,339 @@ impl Iterator for BresenhamLineInclusiveIter {
         }
     }
 }
+
+/*
+//////////////////////////////////////////////////////////////////////////////////////////
+/// Symmetric Bresenham Algo
+//////////////////////////////////////////////////////////////////////////////////////////
+*/
+/// Line-drawing iterator which visits the same points from `start` to `end` as from `end` to
+/// `start`.
+///
+/// Bresenham rounds ties away from `start`, this rounds ties towards the lower absolute
+/// coordinate instead so the rounding does not depend on the direction.
+#[derive(Debug, Clone)]
+pub struct SymmetricLineIter {
+    start: (i32, i32),
+    x_major: bool,
+    major_sign: i32,
+    minor_delta: i64,
+    steps: i32,
+    step: i32,
+}
+
+impl SymmetricLineIter {
+    /// Creates a new iterator. Yields points `start..=end`.
+    #[inline]
+    pub fn new(start: (i32, i32), end: (i32, i32)) -> Self {
+        let delta = (end.0 - start.0, end.1 - start.1);
+        let x_major = delta.0.abs() >= delta.1.abs();
+        let (major, minor) = if x_major { delta } else { (delta.1, delta.0) };
+
+        Self {
+            start,
+            x_major,
+            major_sign: major.signum(),
+            minor_delta: minor as i64,
+            steps: major.abs(),
+            step: 0,
+        }
+    }
+
+    /// Get the point `step` steps along the line without checking if we are past `end`.
+    #[inline]
+    pub(crate) fn point_at(&self, step: i32) -> (i32, i32) {
+        let minor = if self.steps == 0 {
+            0
+        } else {
+            round_half_down(step as i64 * self.minor_delta, self.steps as i64) as i32
+        };
+        let major = step * self.major_sign;
+        if self.x_major {
+            (self.start.0 + major, self.start.1 + minor)
+        } else {
+            (self.start.0 + minor, self.start.1 + major)
+        }
+    }
+
+    /// Get the step at which the line passes `position`'s row or column.
+    #[inline]
+    pub(crate) fn step_of(&self, position: (i32, i32)) -> Option<i32> {
+        let major = if self.x_major {
+            position.0 - self.start.0
+        } else {
+            position.1 - self.start.1
+        };
+        if self.major_sign == 0 {
+            return (major == 0).then_some(0);
+        }
+        let step = major * self.major_sign;
+        (0..=self.steps).contains(&step).then_some(step)
+    }
+}
+
+impl Iterator for SymmetricLineIter {
+    type Item = (i32, i32);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step > self.steps {
+            None
+        } else {
+            self.step += 1;
+            Some(self.point_at(self.step - 1))
+        }
+    }
+}
+
+/// Rounds `numerator / denominator` to the nearest integer, rounding ties down.
+///
+/// `denominator` must be positive.
+#[inline]
+fn round_half_down(numerator: i64, denominator: i64) -> i64 {
+    // ceil(n / d - 1 / 2)
+    -(-(2 * numerator - denominator)).div_euclid(2 * denominator)
+}
+
+/*
+//////////////////////////////////////////////////////////////////////////////////////////
+/// Supercover Algo
+//////////////////////////////////////////////////////////////////////////////////////////
+*/
+/// Line-drawing iterator which yields every point the line passes through.
+///
+/// Where the line passes exactly through a corner both points sharing that corner are
+/// yielded before the diagonal point.
+#[derive(Debug, Clone)]
+pub struct SupercoverLineIter {
+    current: (i32, i32),
+    sign: (i32, i32),
+    delta: (i64, i64),
+    steps: (i64, i64),
+
+    /// Points waiting to be yielded, last first.
+    pending: [(i32, i32); 3],
+    pending_len: usize,
+}
+
+impl SupercoverLineIter {
+    /// Creates a new iterator. Yields points `start..=end`.
+    #[inline]
+    pub fn new(start: (i32, i32), end: (i32, i32)) -> Self {
+        Self {
+            current: start,
+            sign: ((end.0 - start.0).signum(), (end.1 - start.1).signum()),
+            delta: (
+                (end.0 - start.0).abs() as i64,
+                (end.1 - start.1).abs() as i64,
+            ),
+            steps: (0, 0),
+            pending: [start; 3],
+            pending_len: 1,
+        }
+    }
+
+    /// Queues the points up to and including the next step along the line.
+    fn queue_step(&mut self) {
+        if self.steps.0 >= self.delta.0 && self.steps.1 >= self.delta.1 {
+            return;
+        }
+
+        let (x, y) = self.current;
+        let (step_x, step_y) = (x + self.sign.0, y + self.sign.1);
+
+        // compare where the line crosses the next vertical and horizontal edges
+        let decision =
+            (1 + 2 * self.steps.0) * self.delta.1 - (1 + 2 * self.steps.1) * self.delta.0;
+        if decision == 0 {
+            self.current = (step_x, step_y);
+            self.steps = (self.steps.0 + 1, self.steps.1 + 1);
+            self.pending = [self.current, (x, step_y), (step_x, y)];
+            self.pending_len = 3;
+        } else {
+            if decision < 0 {
+                self.current = (step_x, y);
+                self.steps.0 += 1;
+            } else {
+                self.current = (x, step_y);
+                self.steps.1 += 1;
+            }
+            self.pending[0] = self.current;
+            self.pending_len = 1;
+        }
+    }
+}
+
+impl Iterator for SupercoverLineIter {
+    type Item = (i32, i32);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending_len == 0 {
+            self.queue_step();
+        }
+        if self.pending_len == 0 {
+            None
+        } else {
+            self.pending_len -= 1;
+            Some(self.pending[self.pending_len])
+        }
+    }
+}
+
+/*
+//////////////////////////////////////////////////////////////////////////////////////////
+/// DDA Algo
+//////////////////////////////////////////////////////////////////////////////////////////
+*/
+/// Line-drawing iterator stepping a floating point position along the longest axis.
+///
+/// NOTE: Ties are rounded away from zero, so swapping `start` and `end` may visit different
+/// points, see [`SymmetricLineIter`].
+#[derive(Debug, Clone)]
+pub struct DdaLineIter {
+    start: (i32, i32),
+    increment: (f64, f64),
+    x_major: bool,
+    steps: i32,
+    step: i32,
+}
+
+impl DdaLineIter {
+    /// Creates a new iterator. Yields points `start..=end`.
+    #[inline]
+    pub fn new(start: (i32, i32), end: (i32, i32)) -> Self {
+        let delta = (end.0 - start.0, end.1 - start.1);
+        let steps = delta.0.abs().max(delta.1.abs());
+        let increment = if steps == 0 {
+            (0.0, 0.0)
+        } else {
+            (delta.0 as f64 / steps as f64, delta.1 as f64 / steps as f64)
+        };
+
+        Self {
+            start,
+            increment,
+            x_major: delta.0.abs() >= delta.1.abs(),
+            steps,
+            step: 0,
+        }
+    }
+
+    /// Get the point `step` steps along the line without checking if we are past `end`.
+    #[inline]
+    pub(crate) fn point_at(&self, step: i32) -> (i32, i32) {
+        (
+            (self.start.0 as f64 + step as f64 * self.increment.0).round() as i32,
+            (self.start.1 as f64 + step as f64 * self.increment.1).round() as i32,
+        )
+    }
+
+    /// Get the step at which the line passes `position`'s row or column.
+    #[inline]
+    pub(crate) fn step_of(&self, position: (i32, i32)) -> Option<i32> {
+        let step = if self.x_major {
+            (position.0 - self.start.0).abs()
+        } else {
+            (position.1 - self.start.1).abs()
+        };
+        (step <= self.steps).then_some(step)
+    }
+}
+
+impl Iterator for DdaLineIter {
+    type Item = (i32, i32);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step > self.steps {
+            None
+        } else {
+            self.step += 1;
+            Some(self.point_at(self.step - 1))
+        }
+    }
+}
+
+/*
+//////////////////////////////////////////////////////////////////////////////////////////
+/// 3d Bresenham Algo
+//////////////////////////////////////////////////////////////////////////////////////////
+*/
+/// 3d line-drawing iterator, stepping once along the longest axis for every point.
+#[derive(Debug, Clone)]
+pub struct BresenhamLine3Iter {
+    start: (i32, i32, i32),
+    delta: (i32, i32, i32),
+    steps: i32,
+    step: i32,
+}
+
+impl BresenhamLine3Iter {
+    /// Creates a new iterator. Yields points `start..=end`.
+    #[inline]
+    pub fn new(start: (i32, i32, i32), end: (i32, i32, i32)) -> Self {
+        let delta = (end.0 - start.0, end.1 - start.1, end.2 - start.2);
+        Self {
+            start,
+            delta,
+            steps: delta.0.abs().max(delta.1.abs()).max(delta.2.abs()),
+            step: 0,
+        }
+    }
+
+    /// Get the point `step` steps along the line without checking if we are past `end`.
+    #[inline]
+    pub(crate) fn point_at(&self, step: i32) -> (i32, i32, i32) {
+        (
+            self.start.0 + self.offset_at(self.delta.0, step),
+            self.start.1 + self.offset_at(self.delta.1, step),
+            self.start.2 + self.offset_at(self.delta.2, step),
+        )
+    }
+
+    /// Get the step at which the line passes `position`'s layer along the longest axis.
+    #[inline]
+    pub(crate) fn step_of(&self, position: (i32, i32, i32)) -> Option<i32> {
+        let offset = (
+            position.0 - self.start.0,
+            position.1 - self.start.1,
+            position.2 - self.start.2,
+        );
+        let step = if self.steps == 0 {
+            0
+        } else if self.delta.0.abs() == self.steps {
+            offset.0 * self.delta.0.signum()
+        } else if self.delta.1.abs() == self.steps {
+            offset.1 * self.delta.1.signum()
+        } else {
+            offset.2 * self.delta.2.signum()
+        };
+        (0..=self.steps).contains(&step).then_some(step)
+    }
+
+    /// The offset along an axis travelling `delta`, rounding halves away from `start`.
+    #[inline]
+    fn offset_at(&self, delta: i32, step: i32) -> i32 {
+        if self.steps == 0 {
+            return 0;
+        }
+        let distance =
+            (2 * step as i64 * delta.abs() as i64 + self.steps as i64) / (2 * self.steps as i64);
+        distance as i32 * delta.signum()
+    }
+}
+
+impl Iterator for BresenhamLine3Iter {
+    type Item = (i32, i32, i32);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step > self.steps {
+            None
+        } else {
+            self.step += 1;
+            Some(self.point_at(self.step - 1))
+        }
+    }
+}
@@ -2,3 +2,17 @@ mod rect_iter;
 pub use rect_iter::*;
 mod line_iter;
 pub use line_iter::*;
+mod circle_iter;
+pub use circle_iter::*;
+mod rect_border_iter;
+pub use rect_border_iter::*;
+mod circumference_iter;
+pub use circumference_iter::*;
+mod filtered_rect_iter;
+pub use filtered_rect_iter::*;
+mod shape_filter_iter;
+pub use shape_filter_iter::*;
+mod curve_iter;
+pub use curve_iter::*;
+mod cuboid_iter;
+pub use cuboid_iter::*;
@@ -0,0 +1,55 @@
+use crate::grid_shapes::Rectangle;
+
+/// An iterator over the border of a rectangle.
+///
+/// Points are yielded in the same row-major order as [`RectIter`](super::RectIter), skipping
+/// the interior of the rectangle.
+#[derive(Debug, Clone)]
+pub struct RectBorderIter {
+    offset: (i32, i32),
+    max_offset: (i32, i32),
+
+    /// The minimum corner point of the rect.
+    min: (i32, i32),
+}
+
+impl RectBorderIter {
+    /// Creates a new rectangle border iterator.
+    pub fn new(min: (i32, i32), max: (i32, i32)) -> Self {
+        let size = (max.0 - min.0, max.1 - min.1);
+        Self {
+            min,
+            max_offset: size,
+            offset: (0, 0),
+        }
+    }
+}
+
+impl Iterator for RectBorderIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset.1 > self.max_offset.1 {
+            return None;
+        }
+        let p = self.offset;
+        let is_edge_row = p.1 == 0 || p.1 == self.max_offset.1;
+        if is_edge_row || p.0 == self.max_offset.0 {
+            self.offset.0 += 1;
+        } else {
+            // jump across the interior of the rectangle
+            self.offset.0 = self.max_offset.0;
+        }
+        if self.offset.0 > self.max_offset.0 {
+            self.offset.0 = 0;
+            self.offset.1 += 1;
+        }
+        Some((self.min.0 + p.0, self.min.1 + p.1))
+    }
+}
+
+impl From<Rectangle> for RectBorderIter {
+    fn from(rect: Rectangle) -> Self {
+        Self::new(rect.min, rect.max)
+    }
+}
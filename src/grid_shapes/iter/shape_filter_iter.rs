@@ -0,0 +1,40 @@
+use crate::grid_shapes::Shape;
+
+/// An iterator over the points of another iterator which are either inside or outside of a
+/// shape.
+#[derive(Debug, Clone)]
+pub struct ShapeFilterIter<I, S> {
+    iter: I,
+    shape: S,
+    inside: bool,
+}
+
+impl<I, S: Shape> ShapeFilterIter<I, S> {
+    /// Creates a new iterator over the points of `iter` which are inside of `shape`.
+    pub fn inside(iter: I, shape: S) -> Self {
+        Self {
+            iter,
+            shape,
+            inside: true,
+        }
+    }
+
+    /// Creates a new iterator over the points of `iter` which are outside of `shape`.
+    pub fn outside(iter: I, shape: S) -> Self {
+        Self {
+            iter,
+            shape,
+            inside: false,
+        }
+    }
+}
+
+impl<I: Iterator<Item = (i32, i32)>, S: Shape> Iterator for ShapeFilterIter<I, S> {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (shape, inside) = (&self.shape, self.inside);
+        self.iter
+            .find(|&position| shape.contains(position) == inside)
+    }
+}
@@ -1,11 +1,11 @@
-use std::{collections::HashSet, fmt::Display};
+use std::fmt::Display;
 
 use bevy::prelude::Reflect;
 use serde::{Deserialize, Serialize};
 
 use crate::grid_shapes::{
     iter::{BresenhamLineInclusiveIter, BresenhamLineIter},
-    BoxedShape, BoxedShapeIter, Shape, ShapeIter,
+    BoxedShape, BoxedShapeIter, Octant, Rectangle, Shape, ShapeIter, ShapeWithBorder,
 };
 
 /// A line.
@@ -35,21 +35,60 @@ impl Shape for Line {
         (self.end.0 - self.start.0)
             .abs()
             .max((self.end.1 - self.start.1).abs()) as u32
+            + 1
     }
 
+    /// Solves the Bresenham step for `position` directly instead of walking the line.
     #[inline]
     fn contains(&self, position: (i32, i32)) -> bool {
-        self.get_positions().contains(&position)
+        let octant = Octant::new(self.start, self.end);
+        let start = octant.to_offset(self.start);
+        let end = octant.to_offset(self.end);
+        let position = octant.to_offset(position);
+
+        // In `Octant(0)` the line always steps along `X`, and `Y` has been stepped once every
+        // time `delta_y` accumulates past `delta_x`.
+        let delta_x = (end.0 - start.0) as i64;
+        let delta_y = (end.1 - start.1) as i64;
+        let step = (position.0 - start.0) as i64;
+        if step < 0 || step > delta_x {
+            return false;
+        }
+
+        let y = if delta_x == 0 {
+            0
+        } else {
+            step * delta_y / delta_x
+        };
+        (position.1 - start.1) as i64 == y
     }
 
     #[inline]
-    fn get_positions(&self) -> HashSet<(i32, i32)> {
-        self.iter().collect()
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
     }
 
     #[inline]
-    fn boxed_iter(&self) -> BoxedShapeIter {
-        Box::new(self.into_iter())
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.start, self.end)
+    }
+}
+
+/// A line is all border.
+impl ShapeWithBorder for Line {
+    #[inline]
+    fn get_border_count(&self) -> usize {
+        self.get_count() as usize
+    }
+
+    #[inline]
+    fn border_contains(&self, position: (i32, i32)) -> bool {
+        self.contains(position)
+    }
+
+    #[inline]
+    fn border_iter(&self) -> BoxedShapeIter<'_> {
+        self.boxed_iter()
     }
 }
 
@@ -87,3 +126,32 @@ impl From<Line> for BoxedShape {
         Box::new(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::Line;
+    use crate::grid_shapes::{Shape, ShapeIter};
+
+    #[test]
+    fn test_line_contains_and_count() {
+        for end in crate::grid_shapes::Rectangle::new((-9, -9), (9, 9)) {
+            let line = Line::new((0, 0), end);
+            let points: Vec<(i32, i32)> = line.iter().collect();
+            let unique: HashSet<(i32, i32)> = points.iter().copied().collect();
+            assert_eq!(points.len(), unique.len(), "{line}");
+            assert_eq!(line.get_count() as usize, points.len(), "{line}");
+            assert_eq!(points.first(), Some(&(0, 0)), "{line}");
+            assert_eq!(points.last(), Some(&end), "{line}");
+
+            for position in crate::grid_shapes::Rectangle::new((-11, -11), (11, 11)) {
+                assert_eq!(
+                    line.contains(position),
+                    unique.contains(&position),
+                    "{line} {position:?}"
+                );
+            }
+        }
+    }
+}
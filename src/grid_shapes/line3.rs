@@ -0,0 +1,135 @@
+use std::fmt::Display;
+
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::BresenhamLine3Iter, BoxedShape3, BoxedShape3Iter, Cuboid, Shape3, Shape3Iter,
+};
+
+/// A 3d line.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Line3 {
+    end: (i32, i32, i32),
+    start: (i32, i32, i32),
+}
+
+impl Line3 {
+    /// Creates a new 3d line.
+    #[inline(always)]
+    pub const fn new(start: (i32, i32, i32), end: (i32, i32, i32)) -> Self {
+        Self { start, end }
+    }
+}
+
+impl Shape3 for Line3 {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        (self.end.0 - self.start.0)
+            .abs()
+            .max((self.end.1 - self.start.1).abs())
+            .max((self.end.2 - self.start.2).abs()) as u32
+            + 1
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32, i32)) -> bool {
+        let iter = self.iter();
+        iter.step_of(position)
+            .is_some_and(|step| iter.point_at(step) == position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShape3Iter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Cuboid {
+        Cuboid::new(self.start, self.end)
+    }
+}
+
+impl Shape3Iter for Line3 {
+    type Iterator = BresenhamLine3Iter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Line3 {
+    type IntoIter = BresenhamLine3Iter;
+    type Item = (i32, i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        BresenhamLine3Iter::new(self.start, self.end)
+    }
+}
+
+impl Display for Line3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Line3 {{Start: ({}, {}, {}), End: ({}, {}, {})}}",
+            self.start.0, self.start.1, self.start.2, self.end.0, self.end.1, self.end.2
+        )
+    }
+}
+
+impl From<Line3> for BoxedShape3 {
+    fn from(value: Line3) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::Line3;
+    use crate::{
+        direction::Direction,
+        grid_shapes::{Cuboid, Shape3, Shape3Iter},
+    };
+
+    #[test]
+    fn test_line3() {
+        // follows `coord3d`
+        for direction in Direction::iter_all_3d() {
+            let (x, y, z) = direction.coord3d();
+            let line = Line3::new((1, 1, 1), (1 + 4 * x, 1 + 4 * y, 1 + 4 * z));
+            let expected: Vec<(i32, i32, i32)> =
+                (0..=4).map(|i| (1 + i * x, 1 + i * y, 1 + i * z)).collect();
+            if (x, y, z) != (0, 0, 0) {
+                assert_eq!(line.iter().collect::<Vec<_>>(), expected, "{direction:?}");
+            }
+        }
+
+        for end in Cuboid::new((-5, -5, -5), (5, 5, 5)) {
+            let line = Line3::new((0, 0, 0), end);
+            let points: Vec<(i32, i32, i32)> = line.iter().collect();
+            let unique: HashSet<(i32, i32, i32)> = points.iter().copied().collect();
+            assert_eq!(points.len(), unique.len());
+            assert_eq!(points.len() as u32, line.get_count());
+            assert_eq!((points[0], points[points.len() - 1]), ((0, 0, 0), end));
+            for pair in points.windows(2) {
+                let step = (
+                    pair[1].0 - pair[0].0,
+                    pair[1].1 - pair[0].1,
+                    pair[1].2 - pair[0].2,
+                );
+                assert_eq!(step.0.abs().max(step.1.abs()).max(step.2.abs()), 1);
+            }
+            for position in Cuboid::new((-6, -6, -6), (6, 6, 6)) {
+                assert_eq!(
+                    line.contains(position),
+                    unique.contains(&position),
+                    "{line}"
+                );
+            }
+        }
+    }
+}
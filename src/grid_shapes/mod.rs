@@ -10,3 +10,51 @@ mod rectangle;
 pub use rectangle::*;
 mod shape;
 pub use shape::*;
+mod cone;
+pub use cone::*;
+mod cross;
+pub use cross::*;
+mod diamond;
+pub use diamond::*;
+mod ellipse;
+pub use ellipse::*;
+mod polygon;
+pub use polygon::*;
+mod ring;
+pub use ring::*;
+mod triangle;
+pub use triangle::*;
+mod composite;
+pub use composite::*;
+mod shape_def;
+pub use shape_def::*;
+mod transform;
+pub use transform::*;
+mod dda_line;
+pub use dda_line::*;
+mod supercover_line;
+pub use supercover_line::*;
+mod symmetric_line;
+pub use symmetric_line::*;
+mod thick_line;
+pub use thick_line::*;
+mod arc;
+pub use arc::*;
+mod bezier;
+pub use bezier::*;
+mod spiral;
+pub use spiral::*;
+mod shape3;
+pub use shape3::*;
+mod cuboid;
+pub use cuboid::*;
+mod cylinder;
+pub use cylinder::*;
+mod line3;
+pub use line3::*;
+mod sphere;
+pub use sphere::*;
+mod distance_range;
+pub use distance_range::*;
+mod distance_ring;
+pub use distance_ring::*;
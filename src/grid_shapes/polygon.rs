@@ -0,0 +1,164 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    edge_function, iter::FilteredRectIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+};
+
+/// A filled polygon, including the points on its edges.
+///
+/// The interior is determined with the even-odd rule, so self-intersecting polygons leave
+/// holes where they overlap.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Eq, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<(i32, i32)>,
+}
+
+impl Polygon {
+    /// Creates a new polygon, the last vertex connects back to the first.
+    pub const fn new(vertices: Vec<(i32, i32)>) -> Self {
+        Self { vertices }
+    }
+}
+
+impl Polygon {
+    /// Get the vertices of the polygon
+    #[inline]
+    pub fn vertices(&self) -> &[(i32, i32)] {
+        &self.vertices
+    }
+
+    /// Iterates the edges of the polygon as `(start, end)` pairs
+    fn edges(&self) -> impl Iterator<Item = ((i32, i32), (i32, i32))> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+}
+
+impl Shape for Polygon {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.iter().count() as u32
+    }
+
+    fn contains(&self, position: (i32, i32)) -> bool {
+        if self.vertices.is_empty() {
+            return false;
+        }
+
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let side = edge_function(a, b, position);
+            let between = |p: i32, a: i32, b: i32| a.min(b) <= p && p <= a.max(b);
+            if side == 0 && between(position.0, a.0, b.0) && between(position.1, a.1, b.1) {
+                // on an edge
+                return true;
+            }
+
+            // count the edges crossed by a ray cast towards +x
+            if (a.1 > position.1) != (b.1 > position.1) && (side > 0) == (b.1 > a.1) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let Some(&first) = self.vertices.first() else {
+            return Rectangle::default();
+        };
+        let (min, max) = self.vertices.iter().fold((first, first), |(min, max), p| {
+            (
+                (min.0.min(p.0), min.1.min(p.1)),
+                (max.0.max(p.0), max.1.max(p.1)),
+            )
+        });
+        Rectangle::new(min, max)
+    }
+}
+
+impl ShapeIter for Polygon {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.clone().into_iter()
+    }
+}
+
+impl IntoIterator for Polygon {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<Polygon> for BoxedShape {
+    fn from(value: Polygon) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polygon;
+    use crate::grid_shapes::{Rectangle, Shape, ShapeIter, Triangle};
+
+    #[test]
+    fn test_polygon_count() {
+        assert_eq!(Polygon::new(vec![]).iter().count(), 0);
+        assert_eq!(Polygon::new(vec![(2, 3)]).iter().count(), 1);
+        assert_eq!(Polygon::new(vec![(0, 0), (3, 0)]).iter().count(), 4);
+
+        // a rectangle
+        let polygon = Polygon::new(vec![(0, 0), (4, 0), (4, 3), (0, 3)]);
+        assert_eq!(polygon.get_count(), 20);
+        assert!(Rectangle::new((0, 0), (4, 3))
+            .into_iter()
+            .all(|p| polygon.contains(p)));
+
+        // an L shape
+        let polygon = Polygon::new(vec![(0, 0), (4, 0), (4, 1), (1, 1), (1, 4), (0, 4)]);
+        assert_eq!(polygon.get_count(), 10 + 6);
+        assert!(!polygon.contains((2, 2)));
+
+        // matches a triangle
+        let triangle = Triangle::new((0, 0), (9, 2), (3, 7));
+        let polygon = Polygon::new(triangle.vertices().to_vec());
+        assert_eq!(
+            polygon.iter().collect::<Vec<_>>(),
+            triangle.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_polygon_descending_edge() {
+        // the hypotenuse runs from (4, 0) up to (0, 4)
+        let vertices = [(0, 0), (4, 0), (0, 4)];
+        let polygon = Polygon::new(vertices.to_vec());
+        let triangle = Triangle::new(vertices[0], vertices[1], vertices[2]);
+        for point in [(4, 0), (3, 1), (2, 2), (1, 3), (0, 4)] {
+            assert!(polygon.contains(point), "{point:?}");
+            assert!(triangle.contains(point), "{point:?}");
+        }
+        for point in Rectangle::new((-1, -1), (5, 5)) {
+            assert_eq!(
+                polygon.contains(point),
+                triangle.contains(point),
+                "{point:?}"
+            );
+        }
+        assert_eq!(polygon.get_positions(), triangle.get_positions());
+    }
+}
@@ -1,7 +1,13 @@
 use bevy::prelude::Reflect;
 use serde::{Deserialize, Serialize};
 
-use crate::grid_shapes::iter::RectIter;
+use crate::{
+    direction::{Direction, YAxis},
+    grid_shapes::{
+        iter::{RectBorderIter, RectIter},
+        BoxedShape, BoxedShapeIter, Shape, ShapeIter, ShapeWithBorder,
+    },
+};
 
 /// A 2D rectangle.
 #[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
@@ -23,8 +29,8 @@ impl Rectangle {
     #[inline]
     pub fn new(min: (i32, i32), max: (i32, i32)) -> Self {
         Self {
-            min: min.min(max),
-            max: min.max(max),
+            min: (min.0.min(max.0), min.1.min(max.1)),
+            max: (min.0.max(max.0), min.1.max(max.1)),
         }
     }
 
@@ -67,6 +73,25 @@ impl Rectangle {
         let diff = (self.max.0 - self.min.0, self.max.1 - self.min.1);
         diff.0 == diff.1
     }
+
+    /// Get the number of points in the rectangle.
+    #[inline]
+    pub const fn area(&self) -> u32 {
+        ((self.width() + 1) * (self.height() + 1)) as u32
+    }
+
+    /// Get the corners of the rectangle.
+    ///
+    /// Ordered bottom left, bottom right, top right, top left.
+    #[inline]
+    pub const fn corners(&self) -> [(i32, i32); 4] {
+        [
+            self.min,
+            (self.max.0, self.min.1),
+            self.max,
+            (self.min.0, self.max.1),
+        ]
+    }
 }
 
 impl Rectangle {
@@ -95,6 +120,24 @@ impl Rectangle {
         self.min.1.min(self.max.1)
     }
 
+    /// Get the `Y` of the top row with `NORTH` pointing along `y_axis`
+    #[inline]
+    pub fn top_in(&self, y_axis: YAxis) -> i32 {
+        match y_axis {
+            YAxis::Up => self.top(),
+            YAxis::Down => self.bottom(),
+        }
+    }
+
+    /// Get the `Y` of the bottom row with `NORTH` pointing along `y_axis`
+    #[inline]
+    pub fn bottom_in(&self, y_axis: YAxis) -> i32 {
+        match y_axis {
+            YAxis::Up => self.bottom(),
+            YAxis::Down => self.top(),
+        }
+    }
+
     /// Check if this rectangle intersects another rectangle.
     #[inline]
     pub const fn intersects(&self, other: Self) -> bool {
@@ -105,6 +148,130 @@ impl Rectangle {
             && self.max.1 >= other.min.1
     }
 
+    /// Check if the point is inside the rectangle.
+    #[inline]
+    pub const fn contains(&self, point: (i32, i32)) -> bool {
+        point.0 >= self.min.0
+            && point.0 <= self.max.0
+            && point.1 >= self.min.1
+            && point.1 <= self.max.1
+    }
+
+    /// Check if every point of another rectangle is inside this rectangle.
+    #[inline]
+    pub const fn contains_rect(&self, other: Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Get the overlapping area of two rectangles.
+    #[inline]
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Self::new(
+            (self.min.0.max(other.min.0), self.min.1.max(other.min.1)),
+            (self.max.0.min(other.max.0), self.max.1.min(other.max.1)),
+        ))
+    }
+
+    /// Get the smallest rectangle containing both rectangles.
+    #[inline]
+    pub fn union(&self, other: Self) -> Self {
+        Self::new(
+            (self.min.0.min(other.min.0), self.min.1.min(other.min.1)),
+            (self.max.0.max(other.max.0), self.max.1.max(other.max.1)),
+        )
+    }
+
+    /// Get the rectangle grown by `margin` on every side.
+    #[inline]
+    pub fn expand(&self, margin: u32) -> Self {
+        let margin = margin as i32;
+        Self::new(
+            (self.min.0 - margin, self.min.1 - margin),
+            (self.max.0 + margin, self.max.1 + margin),
+        )
+    }
+
+    /// Get the rectangle shrunk by `margin` on every side.
+    ///
+    /// Returns `None` if nothing would be left.
+    #[inline]
+    pub fn shrink(&self, margin: u32) -> Option<Self> {
+        let margin = margin as i32;
+        let min = (self.min.0 + margin, self.min.1 + margin);
+        let max = (self.max.0 - margin, self.max.1 - margin);
+        if min.0 > max.0 || min.1 > max.1 {
+            None
+        } else {
+            Some(Self::new(min, max))
+        }
+    }
+
+    /// Splits the rectangle into a bottom and top rectangle.
+    ///
+    /// `ratio` is the share of rows given to the bottom rectangle, both rectangles keep at least
+    /// one row. Returns `None` if the rectangle is a single row.
+    pub fn split_horizontal(&self, ratio: f32) -> Option<(Self, Self)> {
+        let rows = split_at(self.height(), ratio)?;
+        Some((
+            Self::new(self.min, (self.max.0, self.min.1 + rows - 1)),
+            Self::new((self.min.0, self.min.1 + rows), self.max),
+        ))
+    }
+
+    /// Splits the rectangle into a left and right rectangle.
+    ///
+    /// `ratio` is the share of columns given to the left rectangle, both rectangles keep at least
+    /// one column. Returns `None` if the rectangle is a single column.
+    pub fn split_vertical(&self, ratio: f32) -> Option<(Self, Self)> {
+        let columns = split_at(self.width(), ratio)?;
+        Some((
+            Self::new(self.min, (self.min.0 + columns - 1, self.max.1)),
+            Self::new((self.min.0 + columns, self.min.1), self.max),
+        ))
+    }
+
+    /// Get the closest point inside the rectangle.
+    #[inline]
+    pub fn clamp_point(&self, point: (i32, i32)) -> (i32, i32) {
+        (
+            point.0.clamp(self.min.0, self.max.0),
+            point.1.clamp(self.min.1, self.max.1),
+        )
+    }
+
+    /// Returns an iterator over the points on one side of the rectangle
+    ///
+    /// A diagonal yields the corner between both sides, `Direction::NONE` yields every point.
+    #[inline]
+    pub fn edge(&self, side: Direction) -> RectIter {
+        self.edge_in(side, YAxis::Up)
+    }
+
+    /// Returns an iterator over the points on one side of the rectangle with `NORTH` pointing
+    /// along `y_axis`, see [`edge`](Self::edge)
+    #[inline]
+    pub fn edge_in(&self, side: Direction, y_axis: YAxis) -> RectIter {
+        let side = y_axis.to_y_up(side);
+        let x = if side.has_east() {
+            (self.max.0, self.max.0)
+        } else if side.has_west() {
+            (self.min.0, self.min.0)
+        } else {
+            (self.min.0, self.max.0)
+        };
+        let y = if side.has_north() {
+            (self.max.1, self.max.1)
+        } else if side.has_south() {
+            (self.min.1, self.min.1)
+        } else {
+            (self.min.1, self.max.1)
+        };
+        RectIter::new((x.0, y.0), (x.1, y.1))
+    }
+
     /// Calls a function for each x/y point in the rectangle
     pub fn for_each<F>(&self, f: F)
     where
@@ -112,6 +279,68 @@ impl Rectangle {
     {
         RectIter::new(self.min, self.max).for_each(f);
     }
+
+    /// Returns an iterator over the points on the border of the rectangle
+    #[inline]
+    pub fn border_points(&self) -> RectBorderIter {
+        RectBorderIter::new(self.min, self.max)
+    }
+}
+
+impl Shape for Rectangle {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.area()
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        Rectangle::contains(self, position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        *self
+    }
+}
+
+impl ShapeWithBorder for Rectangle {
+    #[inline]
+    fn get_border_count(&self) -> usize {
+        if self.width() == 0 || self.height() == 0 {
+            self.get_count() as usize
+        } else {
+            (self.width() as usize + self.height() as usize) * 2
+        }
+    }
+
+    #[inline]
+    fn border_contains(&self, position: (i32, i32)) -> bool {
+        self.contains(position)
+            && (position.0 == self.min.0
+                || position.0 == self.max.0
+                || position.1 == self.min.1
+                || position.1 == self.max.1)
+    }
+
+    #[inline]
+    fn border_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.border_points())
+    }
+}
+
+impl ShapeIter for Rectangle {
+    type Iterator = RectIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
 }
 
 impl IntoIterator for Rectangle {
@@ -123,3 +352,162 @@ impl IntoIterator for Rectangle {
         RectIter::new(self.min, self.max)
     }
 }
+
+impl From<Rectangle> for BoxedShape {
+    fn from(value: Rectangle) -> Self {
+        Box::new(value)
+    }
+}
+
+/// The number of cells given to the first part when splitting `size + 1` cells at `ratio`.
+fn split_at(size: i32, ratio: f32) -> Option<i32> {
+    if size < 1 {
+        return None;
+    }
+    let cells = ((size + 1) as f32 * ratio).round() as i32;
+    Some(cells.clamp(1, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rand::{Rng, SeedableRng};
+    use rand_pcg::Pcg64;
+
+    use super::Rectangle;
+    use crate::{
+        direction::{Direction, YAxis},
+        grid_shapes::{BoxedShape, Shape, ShapeIter, ShapeWithBorder},
+    };
+
+    fn random_rect(rng: &mut Pcg64) -> Rectangle {
+        Rectangle::new(
+            (rng.gen_range(-10..10), rng.gen_range(-10..10)),
+            (rng.gen_range(-10..10), rng.gen_range(-10..10)),
+        )
+    }
+
+    #[test]
+    fn test_rectangle_new() {
+        let rect = Rectangle::new((-3, 7), (-8, 11));
+        assert_eq!(rect.min(), (-8, 7));
+        assert_eq!(rect.max(), (-3, 11));
+        assert_eq!(rect, Rectangle::new((-8, 11), (-3, 7)));
+    }
+
+    #[test]
+    fn test_rectangle_shape() {
+        for size in [(0, 0), (0, 3), (4, 0), (1, 1), (5, 3)] {
+            let rect = Rectangle::new_with_size((-2, 3), size);
+            let shape: BoxedShape = rect.into();
+
+            let points: Vec<(i32, i32)> = rect.iter().collect();
+            assert_eq!(points.len() as u32, shape.get_count());
+            assert_eq!(points, shape.boxed_iter().collect::<Vec<_>>());
+
+            let border: Vec<(i32, i32)> = rect.border_iter().collect();
+            let unique: HashSet<(i32, i32)> = border.iter().copied().collect();
+            assert_eq!(border.len(), unique.len());
+            assert_eq!(border.len(), rect.get_border_count());
+
+            for position in Rectangle::new((-4, 1), (5, 10)) {
+                let on_edge = points.contains(&position)
+                    && (position.0 == rect.left()
+                        || position.0 == rect.right()
+                        || position.1 == rect.top()
+                        || position.1 == rect.bottom());
+                assert_eq!(shape.contains(position), points.contains(&position));
+                assert_eq!(rect.border_contains(position), on_edge);
+                assert_eq!(unique.contains(&position), on_edge);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rectangle_properties() {
+        let mut rng = Pcg64::seed_from_u64(32);
+        for _ in 0..500 {
+            let (a, b) = (random_rect(&mut rng), random_rect(&mut rng));
+            let point = (rng.gen_range(-12..12), rng.gen_range(-12..12));
+
+            let union = a.union(b);
+            assert!(union.contains_rect(a) && union.contains_rect(b));
+            assert_eq!(union, b.union(a));
+
+            match a.intersection(b) {
+                Some(overlap) => {
+                    assert!(a.contains_rect(overlap) && b.contains_rect(overlap));
+                    assert_eq!(
+                        overlap.contains(point),
+                        a.contains(point) && b.contains(point)
+                    );
+                }
+                None => assert!(a.iter().all(|p| !b.contains(p))),
+            }
+
+            let clamped = a.clamp_point(point);
+            assert!(a.contains(clamped));
+            assert_eq!(clamped == point, a.contains(point));
+
+            let margin = rng.gen_range(0..4);
+            assert!(a.expand(margin).contains_rect(a));
+            assert_eq!(a.expand(margin).shrink(margin), Some(a));
+            if let Some(inner) = a.shrink(margin) {
+                assert!(a.contains_rect(inner));
+            }
+
+            let ratio = rng.gen_range(0.0..1.0);
+            for split in [a.split_horizontal(ratio), a.split_vertical(ratio)]
+                .into_iter()
+                .flatten()
+            {
+                assert_eq!(split.0.area() + split.1.area(), a.area());
+                assert!(split.0.intersection(split.1).is_none());
+                assert_eq!(split.0.union(split.1), a);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rectangle_edges() {
+        let rect = Rectangle::new((0, 0), (3, 2));
+        assert_eq!(rect.corners(), [(0, 0), (3, 0), (3, 2), (0, 2)]);
+        assert_eq!(
+            rect.edge(Direction::NORTH).collect::<Vec<_>>(),
+            [(0, 2), (1, 2), (2, 2), (3, 2)]
+        );
+        assert_eq!(
+            rect.edge(Direction::WEST).collect::<Vec<_>>(),
+            [(0, 0), (0, 1), (0, 2)]
+        );
+        assert_eq!(
+            rect.edge(Direction::SOUTH_EAST).collect::<Vec<_>>(),
+            [(3, 0)]
+        );
+        assert_eq!(rect.edge(Direction::NONE).count() as u32, rect.get_count());
+        assert_eq!(Rectangle::new((0, 0), (0, 4)).split_vertical(0.5), None);
+    }
+
+    #[test]
+    fn test_rectangle_y_axis() {
+        let rect = Rectangle::new((0, 0), (3, 2));
+        assert_eq!(rect.top_in(YAxis::Up), 2);
+        assert_eq!(rect.bottom_in(YAxis::Up), 0);
+        assert_eq!(rect.top_in(YAxis::Down), 0);
+        assert_eq!(rect.bottom_in(YAxis::Down), 2);
+
+        for side in Direction::iter_cardinal_ordinal() {
+            assert!(rect.edge_in(side, YAxis::Up).eq(rect.edge(side)));
+        }
+        // north is the top row on screen either way
+        assert!(rect
+            .edge_in(Direction::NORTH, YAxis::Down)
+            .all(|(_, y)| y == rect.top_in(YAxis::Down)));
+        assert_eq!(
+            rect.edge_in(Direction::NORTH_EAST, YAxis::Down)
+                .collect::<Vec<_>>(),
+            [(3, 0)]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::FilteredRectIter, BoxedShape, BoxedShapeIter, Circle, Rectangle, Shape, ShapeIter,
+};
+
+/// A ring (annulus), the points of the `outer_radius` circle which are not part of the
+/// `inner_radius` circle.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Ring {
+    center: (i32, i32),
+    inner_radius: u32,
+    outer_radius: u32,
+}
+
+impl Ring {
+    /// Creates a new ring.
+    pub fn new<R: Into<u32>>(center: (i32, i32), inner_radius: R, outer_radius: R) -> Self {
+        let inner_radius = inner_radius.into();
+        let outer_radius = outer_radius.into();
+        Self {
+            center,
+            inner_radius: inner_radius.min(outer_radius),
+            outer_radius: inner_radius.max(outer_radius),
+        }
+    }
+}
+
+impl Ring {
+    /// Get the center of the ring
+    #[inline]
+    pub const fn center(&self) -> (i32, i32) {
+        self.center
+    }
+
+    /// Get the radius of the hole in the ring
+    #[inline]
+    pub const fn inner_radius(&self) -> u32 {
+        self.inner_radius
+    }
+
+    /// Get the radius of the ring
+    #[inline]
+    pub const fn outer_radius(&self) -> u32 {
+        self.outer_radius
+    }
+
+    /// Get the circle forming the hole in the ring
+    #[inline]
+    pub fn inner(&self) -> Circle {
+        Circle::new(self.center, self.inner_radius)
+    }
+
+    /// Get the circle forming the outside of the ring
+    #[inline]
+    pub fn outer(&self) -> Circle {
+        Circle::new(self.center, self.outer_radius)
+    }
+}
+
+impl Shape for Ring {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.outer().get_count() - self.inner().get_count()
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.outer().contains(position) && !self.inner().contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let radius = self.outer_radius as i32;
+        Rectangle::new(
+            (self.center.0 - radius, self.center.1 - radius),
+            (self.center.0 + radius, self.center.1 + radius),
+        )
+    }
+}
+
+impl ShapeIter for Ring {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Ring {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<Ring> for BoxedShape {
+    fn from(value: Ring) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ring;
+    use crate::grid_shapes::{Shape, ShapeIter};
+
+    #[test]
+    fn test_ring_count() {
+        assert_eq!(Ring::new((0, 0), 0u32, 0u32).iter().count(), 0);
+        assert_eq!(Ring::new((0, 0), 0u32, 1u32).iter().count(), 4);
+        for outer in 0..24u32 {
+            for inner in 0..=outer {
+                let ring = Ring::new((5, 5), inner, outer);
+                assert_eq!(ring.iter().count() as u32, ring.get_count());
+                assert!(ring.iter().all(|p| ring.outer().contains(p)));
+                assert!(!ring.iter().any(|p| ring.inner().contains(p)));
+            }
+        }
+    }
+}
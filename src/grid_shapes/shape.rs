@@ -1,10 +1,12 @@
 use std::collections::HashSet;
 
+use crate::grid_shapes::Rectangle;
+
 /// Boxed shape
 pub type BoxedShape = Box<dyn Shape>;
 
 /// Boxed shape iterator
-pub type BoxedShapeIter = Box<dyn Iterator<Item = (i32, i32)>>;
+pub type BoxedShapeIter<'a> = Box<dyn Iterator<Item = (i32, i32)> + 'a>;
 
 /// A trait for iterating over the points in a shape
 pub trait ShapeIter {
@@ -12,6 +14,8 @@ pub trait ShapeIter {
     type Iterator: Iterator<Item = (i32, i32)>;
 
     /// returns an iterator over all points in the shape, inclusively
+    ///
+    /// The iterator yields every point exactly once in a deterministic order.
     fn iter(&self) -> Self::Iterator;
 }
 
@@ -23,11 +27,62 @@ pub trait Shape {
     /// returns `true` if the point is inside the shape
     fn contains(&self, position: (i32, i32)) -> bool;
 
-    /// returns an iterator over all of the points
-    fn get_positions(&self) -> HashSet<(i32, i32)>;
-
     /// returns a boxed iterator over all of the points
-    fn boxed_iter(&self) -> BoxedShapeIter;
+    fn boxed_iter(&self) -> BoxedShapeIter<'_>;
+
+    /// returns a rectangle containing every point of the shape
+    fn bounds(&self) -> Rectangle;
+
+    /// returns a set of all of the points
+    ///
+    /// NOTE: This allocates, prefer `boxed_iter` or `ShapeIter::iter` where possible.
+    fn get_positions(&self) -> HashSet<(i32, i32)> {
+        self.boxed_iter().collect()
+    }
+}
+
+impl<S: Shape + ?Sized> Shape for Box<S> {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.as_ref().get_count()
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.as_ref().contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        self.as_ref().boxed_iter()
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.as_ref().bounds()
+    }
+}
+
+impl<S: Shape + ?Sized> Shape for &S {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        (**self).get_count()
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        (**self).contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        (**self).boxed_iter()
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        (**self).bounds()
+    }
 }
 
 /// A trait for dealing with 2D shapes with a border
@@ -35,9 +90,16 @@ pub trait ShapeWithBorder: Shape {
     /// returns the number of points on the border
     fn get_border_count(&self) -> usize;
 
-    /// returns `true` if the point is inside the shape
+    /// returns `true` if the point is on the border of the shape
     fn border_contains(&self, position: (i32, i32)) -> bool;
 
-    /// returns an iterator over all of the points
-    fn get_border_positions(&self) -> HashSet<(i32, i32)>;
+    /// returns a boxed iterator over all of the points on the border
+    fn border_iter(&self) -> BoxedShapeIter<'_>;
+
+    /// returns a set of all of the points on the border
+    ///
+    /// NOTE: This allocates, prefer `border_iter` where possible.
+    fn get_border_positions(&self) -> HashSet<(i32, i32)> {
+        self.border_iter().collect()
+    }
 }
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use crate::grid_shapes::Cuboid;
+
+/// Boxed 3d shape
+pub type BoxedShape3 = Box<dyn Shape3>;
+
+/// Boxed 3d shape iterator
+pub type BoxedShape3Iter<'a> = Box<dyn Iterator<Item = (i32, i32, i32)> + 'a>;
+
+/// A trait for iterating over the points in a 3d shape
+pub trait Shape3Iter {
+    /// The type of the iterator
+    type Iterator: Iterator<Item = (i32, i32, i32)>;
+
+    /// returns an iterator over all points in the shape, inclusively
+    ///
+    /// The iterator yields every point exactly once in a deterministic order.
+    fn iter(&self) -> Self::Iterator;
+}
+
+/// A trait for dealing with 3D shapes
+///
+/// Coordinates follow [`Direction::coord3d`](crate::direction::Direction::coord3d), `X` is
+/// `EAST`, `Y` is `NORTH` and `Z` is `UP`.
+pub trait Shape3 {
+    /// returns the number of points in the shape
+    fn get_count(&self) -> u32;
+
+    /// returns `true` if the point is inside the shape
+    fn contains(&self, position: (i32, i32, i32)) -> bool;
+
+    /// returns a boxed iterator over all of the points
+    fn boxed_iter(&self) -> BoxedShape3Iter<'_>;
+
+    /// returns a box containing every point of the shape
+    fn bounds(&self) -> Cuboid;
+
+    /// returns a set of all of the points
+    ///
+    /// NOTE: This allocates, prefer `boxed_iter` or `Shape3Iter::iter` where possible.
+    fn get_positions(&self) -> HashSet<(i32, i32, i32)> {
+        self.boxed_iter().collect()
+    }
+}
+
+impl<S: Shape3 + ?Sized> Shape3 for Box<S> {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.as_ref().get_count()
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32, i32)) -> bool {
+        self.as_ref().contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShape3Iter<'_> {
+        self.as_ref().boxed_iter()
+    }
+
+    #[inline]
+    fn bounds(&self) -> Cuboid {
+        self.as_ref().bounds()
+    }
+}
+
+impl<S: Shape3 + ?Sized> Shape3 for &S {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        (**self).get_count()
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32, i32)) -> bool {
+        (**self).contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShape3Iter<'_> {
+        (**self).boxed_iter()
+    }
+
+    #[inline]
+    fn bounds(&self) -> Cuboid {
+        (**self).bounds()
+    }
+}
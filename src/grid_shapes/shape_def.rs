@@ -0,0 +1,569 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    direction::Direction,
+    distance::Distance,
+    grid_shapes::{
+        Arc, BoxedShape, Circle, Cone, Cross, CubicBezier, DdaLine, Diamond, Difference,
+        DistanceRange, DistanceRing, Ellipse, Intersection, Line, Polygon, QuadraticBezier,
+        Rectangle, Ring, ShapeTransform, Spiral, SupercoverLine, SymmetricLine, ThickLine,
+        Transformed, Triangle, Union, Xor,
+    },
+};
+
+/// A data driven shape definition, authored facing `NORTH` relative to an origin at `(0, 0)`.
+///
+/// Loads from `.shape.ron` files through [`GenericAssetLoader`](crate::generic_asset_loader::GenericAssetLoader):
+///
+/// ```ron
+/// Difference([
+///     Circle(radius: 4),
+///     Circle(radius: 1),
+/// ])
+/// ```
+///
+/// NOTE: Only 2d grid shapes have a definition, 3d and hex shapes do not.
+#[derive(Asset, Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[reflect(no_field_bounds)]
+pub enum ShapeDef {
+    /// A [`Circle`]
+    Circle {
+        #[serde(default)]
+        center: (i32, i32),
+        radius: u32,
+    },
+    /// A [`Line`]
+    Line {
+        #[serde(default)]
+        start: (i32, i32),
+        end: (i32, i32),
+    },
+    /// A [`Rectangle`]
+    Rectangle { min: (i32, i32), max: (i32, i32) },
+    /// A [`Cone`] facing forward
+    Cone {
+        #[serde(default)]
+        origin: (i32, i32),
+        spread: f32,
+        range: u32,
+    },
+    /// A [`Ring`]
+    Ring {
+        #[serde(default)]
+        center: (i32, i32),
+        inner_radius: u32,
+        outer_radius: u32,
+    },
+    /// A [`Diamond`]
+    Diamond {
+        #[serde(default)]
+        center: (i32, i32),
+        radius: u32,
+    },
+    /// A [`Cross`]
+    Cross {
+        #[serde(default)]
+        center: (i32, i32),
+        length: u32,
+    },
+    /// An [`Ellipse`] with a `(horizontal, vertical)` radius
+    Ellipse {
+        #[serde(default)]
+        center: (i32, i32),
+        radius: (u32, u32),
+    },
+    /// A [`Triangle`]
+    Triangle { vertices: [(i32, i32); 3] },
+    /// A [`Polygon`]
+    Polygon { vertices: Vec<(i32, i32)> },
+    /// A [`SymmetricLine`]
+    SymmetricLine {
+        #[serde(default)]
+        start: (i32, i32),
+        end: (i32, i32),
+    },
+    /// A [`SupercoverLine`]
+    SupercoverLine {
+        #[serde(default)]
+        start: (i32, i32),
+        end: (i32, i32),
+    },
+    /// A [`DdaLine`]
+    DdaLine {
+        #[serde(default)]
+        start: (i32, i32),
+        end: (i32, i32),
+    },
+    /// A [`ThickLine`]
+    ThickLine {
+        #[serde(default)]
+        start: (i32, i32),
+        end: (i32, i32),
+        width: u32,
+    },
+    /// A [`QuadraticBezier`]
+    QuadraticBezier {
+        #[serde(default)]
+        start: (i32, i32),
+        control: (i32, i32),
+        end: (i32, i32),
+    },
+    /// A [`CubicBezier`]
+    CubicBezier {
+        #[serde(default)]
+        start: (i32, i32),
+        control1: (i32, i32),
+        control2: (i32, i32),
+        end: (i32, i32),
+    },
+    /// An [`Arc`], angles in radians counter clockwise from `EAST`
+    Arc {
+        #[serde(default)]
+        center: (i32, i32),
+        radius: u32,
+        start_angle: f32,
+        end_angle: f32,
+    },
+    /// A [`Spiral`] starting at `angle` in radians counter clockwise from `EAST`
+    Spiral {
+        #[serde(default)]
+        center: (i32, i32),
+        spacing: f32,
+        turns: f32,
+        #[serde(default)]
+        angle: f32,
+    },
+    /// A [`DistanceRange`]
+    DistanceRange {
+        #[serde(default)]
+        center: (i32, i32),
+        radius: u32,
+        distance: Distance,
+    },
+    /// A [`DistanceRing`]
+    DistanceRing {
+        #[serde(default)]
+        center: (i32, i32),
+        radius: u32,
+        distance: Distance,
+    },
+    /// The [`Union`] of every shape
+    Union(Vec<ShapeDef>),
+    /// The [`Intersection`] of every shape
+    Intersection(Vec<ShapeDef>),
+    /// The [`Difference`] of the first shape and every other shape
+    Difference(Vec<ShapeDef>),
+    /// The [`Xor`] of every shape
+    Xor(Vec<ShapeDef>),
+}
+
+impl ShapeDef {
+    /// The file extensions used to load a [`ShapeDef`]
+    pub const EXTENSIONS: &'static [&'static str] = &["shape.ron"];
+
+    /// Builds the shape with its origin at `position` and its `NORTH` turned to `facing`.
+    ///
+    /// `Circle`, `Ring`, `Ellipse`, `Diamond`, `Cross`, `DistanceRange` and `DistanceRing` look
+    /// the same turned, so only their center is turned and they stay axis-aligned, an `Ellipse`
+    /// swaps its radii on odd quarter turns. `Cone`, `Arc` and `Spiral` turn their angles and
+    /// Bezier curves turn their control points so they stay connected, every other shape is
+    /// turned point by point.
+    ///
+    /// NOTE: A [`Direction`] without a horizontal component faces `NORTH`.
+    pub fn resolve(&self, position: (i32, i32), facing: Direction) -> BoxedShape {
+        let transform = ShapeTransform::from_offset(position).with_rotation(facing);
+        let turn = transform.clockwise_steps() as f32 * FRAC_PI_4;
+
+        let local: BoxedShape = match self {
+            Self::Circle { center, radius } => {
+                return Circle::new(transform.apply(*center), *radius).into()
+            }
+            Self::Line { start, end } => Line::new(*start, *end).into(),
+            Self::Rectangle { min, max } => Rectangle::new(*min, *max).into(),
+            Self::Cone {
+                origin,
+                spread,
+                range,
+            } => {
+                // turning the angle keeps the cone round
+                let facing = if facing.coord() == (0, 0) {
+                    Direction::NORTH
+                } else {
+                    facing
+                };
+                return Cone::new(transform.apply(*origin), facing, *spread, *range).into();
+            }
+            Self::Ring {
+                center,
+                inner_radius,
+                outer_radius,
+            } => return Ring::new(transform.apply(*center), *inner_radius, *outer_radius).into(),
+            Self::Diamond { center, radius } => {
+                return Diamond::new(transform.apply(*center), *radius).into()
+            }
+            Self::Cross { center, length } => {
+                return Cross::new(transform.apply(*center), *length).into()
+            }
+            Self::Ellipse { center, radius } => {
+                let radius = if transform.quarter_turns().is_multiple_of(2) {
+                    *radius
+                } else {
+                    (radius.1, radius.0)
+                };
+                return Ellipse::new(transform.apply(*center), radius).into();
+            }
+            Self::Triangle {
+                vertices: [a, b, c],
+            } => Triangle::new(*a, *b, *c).into(),
+            Self::Polygon { vertices } => Polygon::new(vertices.clone()).into(),
+            Self::SymmetricLine { start, end } => SymmetricLine::new(*start, *end).into(),
+            Self::SupercoverLine { start, end } => SupercoverLine::new(*start, *end).into(),
+            Self::DdaLine { start, end } => DdaLine::new(*start, *end).into(),
+            Self::ThickLine { start, end, width } => ThickLine::new(*start, *end, *width).into(),
+            Self::QuadraticBezier {
+                start,
+                control,
+                end,
+            } => {
+                let [start, control, end] = [start, control, end].map(|p| transform.apply(*p));
+                return QuadraticBezier::new(start, control, end).into();
+            }
+            Self::CubicBezier {
+                start,
+                control1,
+                control2,
+                end,
+            } => {
+                let [start, control1, control2, end] =
+                    [start, control1, control2, end].map(|p| transform.apply(*p));
+                return CubicBezier::new(start, control1, control2, end).into();
+            }
+            Self::Arc {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+            } => {
+                let center = transform.apply(*center);
+                return Arc::new(center, *radius, start_angle - turn, end_angle - turn).into();
+            }
+            Self::Spiral {
+                center,
+                spacing,
+                turns,
+                angle,
+            } => {
+                let spiral = Spiral::new(transform.apply(*center), *spacing, *turns);
+                return spiral.with_angle(angle - turn).into();
+            }
+            Self::DistanceRange {
+                center,
+                radius,
+                distance,
+            } => return DistanceRange::new(transform.apply(*center), *radius, *distance).into(),
+            Self::DistanceRing {
+                center,
+                radius,
+                distance,
+            } => return DistanceRing::new(transform.apply(*center), *radius, *distance).into(),
+            Self::Union(shapes) => {
+                return Self::fold(shapes, position, facing, |a, b| Union::new(a, b).into())
+            }
+            Self::Intersection(shapes) => {
+                return Self::fold(shapes, position, facing, |a, b| {
+                    Intersection::new(a, b).into()
+                })
+            }
+            Self::Difference(shapes) => {
+                return Self::fold(shapes, position, facing, |a, b| {
+                    Difference::new(a, b).into()
+                })
+            }
+            Self::Xor(shapes) => {
+                return Self::fold(shapes, position, facing, |a, b| Xor::new(a, b).into())
+            }
+        };
+
+        Transformed::new(local, transform).into()
+    }
+
+    /// Resolves every shape and combines them from left to right, no shapes resolve to an
+    /// empty shape.
+    fn fold(
+        shapes: &[Self],
+        position: (i32, i32),
+        facing: Direction,
+        combine: impl Fn(BoxedShape, BoxedShape) -> BoxedShape,
+    ) -> BoxedShape {
+        shapes
+            .iter()
+            .map(|shape| shape.resolve(position, facing))
+            .reduce(combine)
+            .unwrap_or_else(|| Polygon::new(Vec::new()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_4;
+
+    use super::ShapeDef;
+    use crate::{
+        direction::Direction,
+        distance::Distance,
+        grid_shapes::{
+            Circle, Cross, CubicBezier, Diamond, DistanceRange, Ellipse, Line, Rectangle, Ring,
+            Shape, ShapeTransform, SymmetricLine,
+        },
+    };
+
+    #[test]
+    fn test_shape_def_rotate() {
+        let mut direction = Direction::NORTH;
+        for _ in 0..8 {
+            let transform = ShapeTransform::IDENTITY.with_rotation(direction);
+            assert_eq!(transform.apply((0, 1)), direction.coord());
+            assert_eq!(transform.apply((0, 3)), {
+                let (x, y) = direction.coord();
+                (x * 3, y * 3)
+            });
+            direction = direction.right45();
+        }
+        let transform = ShapeTransform::IDENTITY.with_rotation(Direction::NORTH_EAST);
+        assert_eq!(transform.apply((1, 2)), (2, 1));
+        assert_eq!(transform.apply((2, 1)), (2, -1));
+    }
+
+    #[test]
+    fn test_shape_def_resolve_round_shapes() {
+        for facing in Direction::iter_cardinal_ordinal() {
+            let placed = ShapeTransform::from_offset((10, -4))
+                .with_rotation(facing)
+                .apply((1, 3));
+            // an odd number of whole quarter turns
+            let quarter_turned = [
+                Direction::EAST,
+                Direction::SOUTH_EAST,
+                Direction::WEST,
+                Direction::NORTH_WEST,
+            ]
+            .contains(&facing);
+
+            let circle = ShapeDef::Circle {
+                center: (1, 3),
+                radius: 4,
+            };
+            assert_eq!(
+                circle.resolve((10, -4), facing).get_positions(),
+                Circle::new(placed, 4u32).get_positions(),
+                "{facing:?}"
+            );
+
+            let ring = ShapeDef::Ring {
+                center: (1, 3),
+                inner_radius: 2,
+                outer_radius: 5,
+            };
+            assert_eq!(
+                ring.resolve((10, -4), facing).get_positions(),
+                Ring::new(placed, 2u32, 5u32).get_positions(),
+                "{facing:?}"
+            );
+
+            let diamond = ShapeDef::Diamond {
+                center: (1, 3),
+                radius: 3,
+            };
+            assert_eq!(
+                diamond.resolve((10, -4), facing).get_positions(),
+                Diamond::new(placed, 3u32).get_positions(),
+                "{facing:?}"
+            );
+
+            let cross = ShapeDef::Cross {
+                center: (1, 3),
+                length: 3,
+            };
+            assert_eq!(
+                cross.resolve((10, -4), facing).get_positions(),
+                Cross::new(placed, 3u32).get_positions(),
+                "{facing:?}"
+            );
+
+            let ellipse = ShapeDef::Ellipse {
+                center: (1, 3),
+                radius: (5, 2),
+            };
+            let radius = if quarter_turned { (2, 5) } else { (5, 2) };
+            assert_eq!(
+                ellipse.resolve((10, -4), facing).get_positions(),
+                Ellipse::new(placed, radius).get_positions(),
+                "{facing:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_shape_def_resolve() {
+        let def = ShapeDef::Line {
+            start: (0, 1),
+            end: (0, 5),
+        };
+        let line = def.resolve((10, 10), Direction::EAST);
+        assert_eq!(
+            line.get_positions(),
+            Line::new((11, 10), (15, 10)).get_positions()
+        );
+
+        let def = ShapeDef::Rectangle {
+            min: (-1, 1),
+            max: (1, 4),
+        };
+        let rect = def.resolve((0, 0), Direction::SOUTH);
+        assert_eq!(
+            rect.get_positions(),
+            Rectangle::new((-1, -4), (1, -1)).get_positions()
+        );
+        let turned = def.resolve((0, 0), Direction::NORTH_EAST);
+        assert_eq!(turned.get_count(), 4 * 3);
+        for corner in [(0, 1), (1, 0), (4, 3), (3, 4)] {
+            assert!(turned.contains(corner));
+        }
+        // the long edges run diagonally
+        for edge in [(2, 1), (3, 2), (1, 2), (2, 3)] {
+            assert!(turned.contains(edge), "{edge:?}");
+        }
+    }
+
+    #[test]
+    fn test_shape_def_lines_and_curves() {
+        let ron = "Union([
+            SymmetricLine(end: (4, 2)),
+            SupercoverLine(start: (1, 1), end: (3, 6)),
+            DdaLine(end: (-2, 5)),
+            ThickLine(end: (0, 6), width: 3),
+            QuadraticBezier(control: (10, 40), end: (1, 0)),
+            CubicBezier(control1: (20, 20), control2: (-10, 20), end: (10, 0)),
+            Arc(radius: 5, start_angle: 0.0, end_angle: 3.0),
+            Spiral(spacing: 1.0, turns: 2.0),
+            DistanceRange(radius: 3, distance: Chebyshev),
+            DistanceRing(center: (0, 4), radius: 2, distance: Manhattan),
+        ])";
+        let def: ShapeDef = ron::from_str(ron).unwrap();
+        assert_eq!(def.resolve((0, 0), Direction::NORTH).get_count(), {
+            let ShapeDef::Union(shapes) = &def else {
+                unreachable!()
+            };
+            shapes
+                .iter()
+                .flat_map(|shape| shape.resolve((0, 0), Direction::NORTH).get_positions())
+                .collect::<std::collections::HashSet<_>>()
+                .len() as u32
+        });
+        let round_trip: ShapeDef = ron::from_str(&ron::to_string(&def).unwrap()).unwrap();
+        assert_eq!(round_trip, def);
+
+        let line = ShapeDef::SymmetricLine {
+            start: (0, 1),
+            end: (2, 5),
+        };
+        let curve = ShapeDef::CubicBezier {
+            start: (0, 0),
+            control1: (20, 20),
+            control2: (-10, 20),
+            end: (10, 0),
+        };
+        let arc = ShapeDef::Arc {
+            center: (0, 0),
+            radius: 6,
+            start_angle: FRAC_PI_4,
+            end_angle: 3.0 * FRAC_PI_4,
+        };
+        let range = ShapeDef::DistanceRange {
+            center: (1, 2),
+            radius: 3,
+            distance: Distance::Pythagoras,
+        };
+        for facing in Direction::iter_cardinal_ordinal() {
+            let transform = ShapeTransform::from_offset((5, 5)).with_rotation(facing);
+
+            // quarter turns move every point of a line
+            if facing.coord().0 == 0 || facing.coord().1 == 0 {
+                assert_eq!(
+                    line.resolve((5, 5), facing).get_positions(),
+                    SymmetricLine::new((0, 1), (2, 5))
+                        .get_positions()
+                        .into_iter()
+                        .map(|position| transform.apply(position))
+                        .collect()
+                );
+            }
+
+            // curves turn their control points
+            let [start, control1, control2, end] =
+                [(0, 0), (20, 20), (-10, 20), (10, 0)].map(|p| transform.apply(p));
+            assert_eq!(
+                curve.resolve((5, 5), facing).get_positions(),
+                CubicBezier::new(start, control1, control2, end).get_positions()
+            );
+
+            // arcs turn their angles and stay round
+            let arc = arc.resolve((5, 5), facing);
+            let (x, y) = facing.as_vec2().normalize().into();
+            let ahead = (5 + (x * 6.0).round() as i32, 5 + (y * 6.0).round() as i32);
+            assert!(arc.contains(ahead), "{facing:?}");
+            for (px, py) in arc.get_positions() {
+                let radius = ((px - 5) as f32).hypot((py - 5) as f32);
+                assert!((radius - 6.0).abs() < 1.0, "{facing:?} {px} {py}");
+            }
+
+            assert_eq!(
+                range.resolve((5, 5), facing).get_positions(),
+                DistanceRange::new(transform.apply((1, 2)), 3, Distance::Pythagoras)
+                    .get_positions()
+            );
+        }
+    }
+
+    #[test]
+    fn test_shape_def_ron() {
+        let ron = "Difference([
+            Circle(radius: 4),
+            Circle(center: (0, 0), radius: 1),
+            Line(end: (0, 6)),
+        ])";
+        let def: ShapeDef = ron::from_str(ron).unwrap();
+        assert_eq!(
+            def,
+            ShapeDef::Difference(vec![
+                ShapeDef::Circle {
+                    center: (0, 0),
+                    radius: 4
+                },
+                ShapeDef::Circle {
+                    center: (0, 0),
+                    radius: 1
+                },
+                ShapeDef::Line {
+                    start: (0, 0),
+                    end: (0, 6)
+                },
+            ])
+        );
+
+        let shape = def.resolve((3, 3), Direction::WEST);
+        let expected = Circle::new((3, 3), 4u32).get_count()
+            - Circle::new((3, 3), 1u32).get_count()
+            - Line::new((3, 3), (-1, 3)).get_count()
+            + Line::new((3, 3), (2, 3)).get_count();
+        assert_eq!(shape.get_count(), expected);
+        assert!(!shape.contains((1, 3)));
+        assert!(shape.contains((3, 5)));
+
+        let round_trip: ShapeDef = ron::from_str(&ron::to_string(&def).unwrap()).unwrap();
+        assert_eq!(round_trip, def);
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::FilteredCuboidIter, BoxedShape3, BoxedShape3Iter, Cuboid, Shape3, Shape3Iter,
+};
+
+/// A Grid based sphere
+///
+/// Contains every point within `radius + 1/2` of the center.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Sphere {
+    center: (i32, i32, i32),
+    radius: u32,
+}
+
+impl Sphere {
+    /// Creates a new sphere.
+    pub fn new<R: Into<u32>>(center: (i32, i32, i32), radius: R) -> Self {
+        Self {
+            center,
+            radius: radius.into(),
+        }
+    }
+}
+
+impl Sphere {
+    /// Get the center of the sphere
+    #[inline]
+    pub const fn center(&self) -> (i32, i32, i32) {
+        self.center
+    }
+
+    /// Get the radius of the sphere
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// The largest squared distance from the center inside the sphere, `(r + 1/2)²` rounded
+    /// down.
+    #[inline]
+    const fn limit(&self) -> i64 {
+        let radius = self.radius as i64;
+        radius * radius + radius
+    }
+}
+
+impl Shape3 for Sphere {
+    /// Sums the width of every row of every layer.
+    fn get_count(&self) -> u32 {
+        let radius = self.radius as i64;
+        let mut count = 0;
+        for z in -radius..=radius {
+            for y in -radius..=radius {
+                let remaining = self.limit() - z * z - y * y;
+                if remaining >= 0 {
+                    count += 2 * remaining.isqrt() + 1;
+                }
+            }
+        }
+        count as u32
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32, i32)) -> bool {
+        let x = (position.0 - self.center.0) as i64;
+        let y = (position.1 - self.center.1) as i64;
+        let z = (position.2 - self.center.2) as i64;
+        x * x + y * y + z * z <= self.limit()
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShape3Iter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Cuboid {
+        let radius = self.radius as i32;
+        Cuboid::new(
+            (
+                self.center.0 - radius,
+                self.center.1 - radius,
+                self.center.2 - radius,
+            ),
+            (
+                self.center.0 + radius,
+                self.center.1 + radius,
+                self.center.2 + radius,
+            ),
+        )
+    }
+}
+
+impl Shape3Iter for Sphere {
+    type Iterator = FilteredCuboidIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Sphere {
+    type IntoIter = FilteredCuboidIter<Self>;
+    type Item = (i32, i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredCuboidIter::new(self)
+    }
+}
+
+impl From<Sphere> for BoxedShape3 {
+    fn from(value: Sphere) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sphere;
+    use crate::{
+        direction::Direction,
+        grid_shapes::{Shape3, Shape3Iter},
+    };
+
+    #[test]
+    fn test_sphere() {
+        assert_eq!(Sphere::new((0, 0, 0), 0u32).get_count(), 1);
+        // the 3x3x3 cube without its corners
+        assert_eq!(Sphere::new((0, 0, 0), 1u32).get_count(), 27 - 8);
+
+        for radius in 0..8u32 {
+            let sphere = Sphere::new((3, -2, 5), radius);
+            let points: Vec<(i32, i32, i32)> = sphere.iter().collect();
+            assert_eq!(points.len() as u32, sphere.get_count());
+
+            // reaches `radius` along every axis
+            for direction in Direction::iter_all_3d() {
+                let (x, y, z) = direction.coord3d();
+                if x.abs() + y.abs() + z.abs() != 1 {
+                    continue;
+                }
+                let (r, past) = (radius as i32, radius as i32 + 1);
+                assert!(sphere.contains((3 + x * r, -2 + y * r, 5 + z * r)));
+                assert!(!sphere.contains((3 + x * past, -2 + y * past, 5 + z * past)));
+            }
+        }
+    }
+}
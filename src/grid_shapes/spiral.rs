@@ -0,0 +1,185 @@
+use std::{f32::consts::TAU, fmt::Display};
+
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::{curve_contains, curve_samples, CurveIter, CurvePathIter},
+    BoxedShape, BoxedShapeIter, Circle, Rectangle, Shape, ShapeIter,
+};
+
+/// An Archimedean spiral winding counter clockwise out of `center`.
+///
+/// Each turn moves `spacing` further from the center, the spiral starts facing `angle` in
+/// radians counter clockwise from `EAST`.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct Spiral {
+    center: (i32, i32),
+    spacing: f32,
+    turns: f32,
+    angle: f32,
+}
+
+impl Spiral {
+    /// Creates a new spiral starting towards `EAST`.
+    pub const fn new(center: (i32, i32), spacing: f32, turns: f32) -> Self {
+        Self {
+            center,
+            spacing,
+            turns,
+            angle: 0.0,
+        }
+    }
+
+    /// Sets the angle the spiral starts facing.
+    pub const fn with_angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+}
+
+impl Spiral {
+    /// Get the center of the spiral
+    #[inline]
+    pub const fn center(&self) -> (i32, i32) {
+        self.center
+    }
+
+    /// Get the distance between each turn of the spiral
+    #[inline]
+    pub const fn spacing(&self) -> f32 {
+        self.spacing
+    }
+
+    /// Get the number of turns of the spiral
+    #[inline]
+    pub const fn turns(&self) -> f32 {
+        self.turns
+    }
+
+    /// Get the angle the spiral starts facing
+    #[inline]
+    pub const fn angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Get the distance of the outer end from the center
+    #[inline]
+    pub fn outer_radius(&self) -> f32 {
+        self.spacing * self.turns
+    }
+
+    /// Get the point `turn` turns along the spiral.
+    pub fn point_at(&self, turn: f32) -> (f32, f32) {
+        let radius = self.spacing * turn;
+        let angle = self.angle + turn * TAU;
+        (
+            self.center.0 as f32 + radius * angle.cos(),
+            self.center.1 as f32 + radius * angle.sin(),
+        )
+    }
+
+    /// Get an iterator over the connected path of the spiral, from the center outwards.
+    #[inline]
+    pub fn path(&self) -> CurvePathIter {
+        CurvePathIter::new(self.samples())
+    }
+
+    fn samples(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        // never shorter than the spiral
+        let length = (self.outer_radius().abs() + 1.0) * self.turns.abs() * TAU;
+        let samples = curve_samples(length);
+        (0..=samples).map(move |i| self.point_at(self.turns * i as f32 / samples as f32))
+    }
+}
+
+impl Shape for Spiral {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.iter().len() as u32
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.bounds().contains(position) && curve_contains(self.samples(), position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Circle::new(self.center, self.outer_radius().abs().ceil() as u32).bounds()
+    }
+}
+
+impl ShapeIter for Spiral {
+    type Iterator = CurveIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        CurveIter::new(self.samples())
+    }
+}
+
+impl IntoIterator for Spiral {
+    type IntoIter = CurveIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Display for Spiral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Spiral {{Center: ({}, {}), Spacing: {}, Turns: {}, Angle: {}}}",
+            self.center.0, self.center.1, self.spacing, self.turns, self.angle
+        )
+    }
+}
+
+impl From<Spiral> for BoxedShape {
+    fn from(value: Spiral) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Spiral;
+    use crate::grid_shapes::bezier::tests::assert_connected;
+
+    #[test]
+    fn test_spiral() {
+        let spiral = Spiral::new((2, 3), 4.0, 3.0);
+        assert_connected(&spiral, spiral.path());
+        let points: Vec<(i32, i32)> = spiral.path().collect();
+        assert_eq!((points[0], points[points.len() - 1]), ((2, 3), (14, 3)));
+        assert_eq!(
+            spiral.to_string(),
+            "Spiral {Center: (2, 3), Spacing: 4, Turns: 3, Angle: 0}"
+        );
+
+        // turns closer than a point overlap
+        for spacing in [0.25, 0.5, 1.0] {
+            let spiral = Spiral::new((0, 0), spacing, 6.0);
+            assert_connected(&spiral, spiral.path());
+        }
+
+        // every turn crosses the starting direction once
+        let crossings: Vec<i32> = points
+            .iter()
+            .filter(|p| p.1 == 3 && p.0 > 2)
+            .map(|p| p.0)
+            .collect();
+        for x in [6, 10, 14] {
+            assert!(crossings.contains(&x), "{crossings:?}");
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::SupercoverLineIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+    ShapeWithBorder,
+};
+
+/// A line covering every point the straight line between the centers of `start` and `end`
+/// touches, including points it only touches at a corner.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SupercoverLine {
+    end: (i32, i32),
+    start: (i32, i32),
+}
+
+impl SupercoverLine {
+    /// Creates a new supercover line.
+    #[inline(always)]
+    pub const fn new(start: (i32, i32), end: (i32, i32)) -> Self {
+        Self { start, end }
+    }
+}
+
+impl Shape for SupercoverLine {
+    /// Every step crosses a column or a row, passing through a corner crosses both and adds the
+    /// two points sharing that corner.
+    #[inline]
+    fn get_count(&self) -> u32 {
+        let delta_x = (self.end.0 - self.start.0).unsigned_abs();
+        let delta_y = (self.end.1 - self.start.1).unsigned_abs();
+
+        let mut corners = 0;
+        if delta_x > 0 && delta_y > 0 {
+            let (mut a, mut b) = (delta_x, delta_y);
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            // the line passes through a corner when both reduced deltas are odd
+            if (delta_x / a) % 2 == 1 && (delta_y / a) % 2 == 1 {
+                corners = a;
+            }
+        }
+        delta_x + delta_y + 1 + corners
+    }
+
+    /// A point is touched when the line passes within half a point of its center on both axes.
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        if !self.bounds().contains(position) {
+            return false;
+        }
+        let delta = (
+            (self.end.0 - self.start.0) as i64,
+            (self.end.1 - self.start.1) as i64,
+        );
+        let offset = (
+            (position.0 - self.start.0) as i64,
+            (position.1 - self.start.1) as i64,
+        );
+        let cross = delta.0 * offset.1 - delta.1 * offset.0;
+        2 * cross.abs() <= delta.0.abs() + delta.1.abs()
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.start, self.end)
+    }
+}
+
+/// A line is all border.
+impl ShapeWithBorder for SupercoverLine {
+    #[inline]
+    fn get_border_count(&self) -> usize {
+        self.get_count() as usize
+    }
+
+    #[inline]
+    fn border_contains(&self, position: (i32, i32)) -> bool {
+        self.contains(position)
+    }
+
+    #[inline]
+    fn border_iter(&self) -> BoxedShapeIter<'_> {
+        self.boxed_iter()
+    }
+}
+
+impl ShapeIter for SupercoverLine {
+    type Iterator = SupercoverLineIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for SupercoverLine {
+    type IntoIter = SupercoverLineIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SupercoverLineIter::new(self.start, self.end)
+    }
+}
+
+impl From<SupercoverLine> for BoxedShape {
+    fn from(value: SupercoverLine) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::SupercoverLine;
+    use crate::grid_shapes::{Rectangle, Shape, ShapeIter, SymmetricLine};
+
+    #[test]
+    fn test_supercover_line() {
+        for end in Rectangle::new((-9, -9), (9, 9)) {
+            let line = SupercoverLine::new((0, 0), end);
+            let points: Vec<(i32, i32)> = line.iter().collect();
+            let unique: HashSet<(i32, i32)> = points.iter().copied().collect();
+            assert_eq!(points.len(), unique.len(), "{end:?}");
+            assert_eq!(points.len() as u32, line.get_count(), "{end:?}");
+            assert_eq!((points[0], points[points.len() - 1]), ((0, 0), end));
+            assert_eq!(SupercoverLine::new(end, (0, 0)).get_positions(), unique);
+
+            // covers the rounded line
+            assert!(SymmetricLine::new((0, 0), end)
+                .iter()
+                .all(|p| unique.contains(&p)));
+
+            for position in Rectangle::new((-11, -11), (11, 11)) {
+                assert_eq!(line.contains(position), unique.contains(&position));
+            }
+        }
+
+        // both points beside the corner are hit
+        assert_eq!(
+            SupercoverLine::new((0, 0), (2, 2))
+                .iter()
+                .collect::<Vec<_>>(),
+            [(0, 0), (1, 0), (0, 1), (1, 1), (2, 1), (1, 2), (2, 2)]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::SymmetricLineIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+    ShapeWithBorder,
+};
+
+/// A line which covers the same points no matter which end it is drawn from.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SymmetricLine {
+    end: (i32, i32),
+    start: (i32, i32),
+}
+
+impl SymmetricLine {
+    /// Creates a new symmetric line.
+    #[inline(always)]
+    pub const fn new(start: (i32, i32), end: (i32, i32)) -> Self {
+        Self { start, end }
+    }
+}
+
+impl Shape for SymmetricLine {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        (self.end.0 - self.start.0)
+            .abs()
+            .max((self.end.1 - self.start.1).abs()) as u32
+            + 1
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        let iter = self.iter();
+        iter.step_of(position)
+            .is_some_and(|step| iter.point_at(step) == position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.start, self.end)
+    }
+}
+
+/// A line is all border.
+impl ShapeWithBorder for SymmetricLine {
+    #[inline]
+    fn get_border_count(&self) -> usize {
+        self.get_count() as usize
+    }
+
+    #[inline]
+    fn border_contains(&self, position: (i32, i32)) -> bool {
+        self.contains(position)
+    }
+
+    #[inline]
+    fn border_iter(&self) -> BoxedShapeIter<'_> {
+        self.boxed_iter()
+    }
+}
+
+impl ShapeIter for SymmetricLine {
+    type Iterator = SymmetricLineIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for SymmetricLine {
+    type IntoIter = SymmetricLineIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SymmetricLineIter::new(self.start, self.end)
+    }
+}
+
+impl From<SymmetricLine> for BoxedShape {
+    fn from(value: SymmetricLine) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SymmetricLine;
+    use crate::grid_shapes::{Line, Rectangle, Shape, ShapeIter};
+
+    #[test]
+    fn test_symmetric_line() {
+        let mut asymmetric = 0;
+        for end in Rectangle::new((-9, -9), (9, 9)) {
+            let line = SymmetricLine::new((0, 0), end);
+            let points: Vec<(i32, i32)> = line.iter().collect();
+            let mut reversed: Vec<(i32, i32)> = SymmetricLine::new(end, (0, 0)).iter().collect();
+            reversed.reverse();
+            assert_eq!(points, reversed, "{end:?}");
+            assert_eq!(points.len() as u32, line.get_count());
+            assert_eq!((points[0], points[points.len() - 1]), ((0, 0), end));
+
+            for position in Rectangle::new((-11, -11), (11, 11)) {
+                assert_eq!(line.contains(position), points.contains(&position));
+            }
+
+            let bresenham = Line::new(end, (0, 0)).get_positions();
+            if Line::new((0, 0), end).get_positions() != bresenham {
+                asymmetric += 1;
+            }
+        }
+        // the lines this exists for
+        assert!(asymmetric > 0);
+    }
+}
@@ -0,0 +1,132 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::FilteredRectIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+};
+
+/// A line with a width.
+///
+/// Covers every point within `width / 2` of the line between `start` and `end`, the ends are
+/// cut off square.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ThickLine {
+    end: (i32, i32),
+    start: (i32, i32),
+    width: u32,
+}
+
+impl ThickLine {
+    /// Creates a new thick line.
+    #[inline(always)]
+    pub const fn new(start: (i32, i32), end: (i32, i32), width: u32) -> Self {
+        Self { start, end, width }
+    }
+}
+
+impl ThickLine {
+    /// Get the width of the line
+    #[inline]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+}
+
+impl Shape for ThickLine {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.iter().count() as u32
+    }
+
+    fn contains(&self, position: (i32, i32)) -> bool {
+        let delta = (
+            (self.end.0 - self.start.0) as i64,
+            (self.end.1 - self.start.1) as i64,
+        );
+        let offset = (
+            (position.0 - self.start.0) as i64,
+            (position.1 - self.start.1) as i64,
+        );
+        let width = self.width as i64;
+        let length = delta.0 * delta.0 + delta.1 * delta.1;
+
+        if length == 0 {
+            return 4 * (offset.0 * offset.0 + offset.1 * offset.1) <= width * width;
+        }
+
+        // past either end
+        let along = delta.0 * offset.0 + delta.1 * offset.1;
+        if along < 0 || along > length {
+            return false;
+        }
+
+        // (2 * distance)² <= width², scaled by the squared length
+        let cross = delta.0 * offset.1 - delta.1 * offset.0;
+        4 * cross * cross <= width * width * length
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.start, self.end).expand(self.width / 2)
+    }
+}
+
+impl ShapeIter for ThickLine {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for ThickLine {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<ThickLine> for BoxedShape {
+    fn from(value: ThickLine) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThickLine;
+    use crate::grid_shapes::{Line, Rectangle, Shape, ShapeIter, SymmetricLine};
+
+    #[test]
+    fn test_thick_line() {
+        assert_eq!(ThickLine::new((0, 0), (5, 0), 3).get_count(), 3 * 6);
+        assert_eq!(
+            ThickLine::new((0, 0), (5, 0), 1).get_positions(),
+            Line::new((0, 0), (5, 0)).get_positions()
+        );
+
+        for end in Rectangle::new((-7, -7), (7, 7)) {
+            let mut previous = ThickLine::new((0, 0), end, 1).get_positions();
+            assert!(SymmetricLine::new((0, 0), end)
+                .iter()
+                .all(|p| previous.contains(&p)));
+
+            for width in 2..5 {
+                let line = ThickLine::new((0, 0), end, width);
+                let points = line.get_positions();
+                assert_eq!(points, ThickLine::new(end, (0, 0), width).get_positions());
+                assert!(previous.is_subset(&points), "{end:?} {width}");
+                previous = points;
+            }
+        }
+    }
+}
@@ -0,0 +1,430 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    direction::Direction,
+    grid_shapes::{BoxedShape, BoxedShapeIter, Octant, Rectangle, Shape, ShapeIter},
+};
+
+/// A transform of the grid, applied as scale, then mirror, then rotation, then offset.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ShapeTransform {
+    /// The offset added after every other step
+    pub offset: (i32, i32),
+    /// The [`Direction`] `NORTH` is rotated to, clockwise in 45° steps
+    ///
+    /// NOTE: A [`Direction`] without a horizontal component does not rotate.
+    pub rotation: Direction,
+    /// Mirror the `X` axis
+    pub mirror_x: bool,
+    /// Mirror the `Y` axis
+    pub mirror_y: bool,
+    /// Every point becomes a `scale * scale` block of points
+    ///
+    /// NOTE: A scale of `0` is treated as `1`.
+    pub scale: u32,
+}
+
+impl Default for ShapeTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl ShapeTransform {
+    /// A transform which leaves every point in place
+    pub const IDENTITY: Self = Self {
+        offset: (0, 0),
+        rotation: Direction::NORTH,
+        mirror_x: false,
+        mirror_y: false,
+        scale: 1,
+    };
+
+    /// Creates a new transform which only moves by `offset`
+    pub const fn from_offset(offset: (i32, i32)) -> Self {
+        Self {
+            offset,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Sets the offset of the transform
+    pub const fn with_offset(mut self, offset: (i32, i32)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the [`Direction`] `NORTH` is rotated to
+    pub const fn with_rotation(mut self, rotation: Direction) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets if the `X` axis is mirrored
+    pub const fn with_mirror_x(mut self, mirror_x: bool) -> Self {
+        self.mirror_x = mirror_x;
+        self
+    }
+
+    /// Sets if the `Y` axis is mirrored
+    pub const fn with_mirror_y(mut self, mirror_y: bool) -> Self {
+        self.mirror_y = mirror_y;
+        self
+    }
+
+    /// Sets the scale of the transform
+    pub const fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+impl ShapeTransform {
+    /// Transforms `position`, the corner of its scaled block closest to `(0, 0)` before
+    /// mirroring and rotating is returned.
+    #[inline]
+    pub fn apply(&self, position: (i32, i32)) -> (i32, i32) {
+        self.apply_scaled(position, (0, 0))
+    }
+
+    /// Converts a transformed position back into the position it was transformed from.
+    pub fn inverse(&self, position: (i32, i32)) -> (i32, i32) {
+        let position = (position.0 - self.offset.0, position.1 - self.offset.1);
+
+        let steps = self.clockwise_steps();
+        let position = if steps % 2 == 1 {
+            ring_rotate(position, false)
+        } else {
+            position
+        };
+        // undo the quarter turns by turning the rest of the way around
+        let position = Octant(quarter_turn_octant(4 - steps / 2)).from_offset(position);
+        let position = self.mirror(position);
+
+        let scale = self.scale() as i32;
+        (position.0.div_euclid(scale), position.1.div_euclid(scale))
+    }
+
+    /// Transforms the point at `sub_position` inside of the scaled block of `position`.
+    fn apply_scaled(&self, position: (i32, i32), sub_position: (i32, i32)) -> (i32, i32) {
+        let scale = self.scale() as i32;
+        let position = (
+            position.0 * scale + sub_position.0,
+            position.1 * scale + sub_position.1,
+        );
+        let position = self.mirror_and_quarter_turn(position);
+        let position = if self.clockwise_steps() % 2 == 1 {
+            ring_rotate(position, true)
+        } else {
+            position
+        };
+
+        (position.0 + self.offset.0, position.1 + self.offset.1)
+    }
+
+    /// Applies the mirror and the 90° steps of the rotation
+    #[inline]
+    fn mirror_and_quarter_turn(&self, position: (i32, i32)) -> (i32, i32) {
+        Octant(quarter_turn_octant(self.clockwise_steps() / 2)).from_offset(self.mirror(position))
+    }
+
+    /// The number of whole 90° clockwise steps from `NORTH` to `rotation`
+    #[inline]
+    pub(crate) fn quarter_turns(&self) -> u8 {
+        self.clockwise_steps() / 2
+    }
+
+    #[inline]
+    fn scale(&self) -> u32 {
+        self.scale.max(1)
+    }
+
+    #[inline]
+    fn mirror(&self, position: (i32, i32)) -> (i32, i32) {
+        (
+            if self.mirror_x {
+                -position.0
+            } else {
+                position.0
+            },
+            if self.mirror_y {
+                -position.1
+            } else {
+                position.1
+            },
+        )
+    }
+
+    /// The number of 45° clockwise steps from `NORTH` to `rotation`
+    pub(crate) fn clockwise_steps(&self) -> u8 {
+        match self.rotation.coord() {
+            (1, 1) => 1,
+            (1, 0) => 2,
+            (1, -1) => 3,
+            (0, -1) => 4,
+            (-1, -1) => 5,
+            (-1, 0) => 6,
+            (-1, 1) => 7,
+            _ => 0,
+        }
+    }
+}
+
+/// The [`Octant`] whose `from_offset` rotates clockwise by `quarter_turns` 90° steps
+#[inline]
+const fn quarter_turn_octant(quarter_turns: u8) -> u8 {
+    match quarter_turns % 4 {
+        0 => 0,
+        1 => 6,
+        2 => 4,
+        _ => 2,
+    }
+}
+
+/// Rotates `position` by 45° around `(0, 0)` by moving it one eighth of the way around its
+/// square ring, so `NORTH` lands on `NORTH_EAST` when `clockwise`.
+///
+/// Unlike a true rotation this maps every point onto exactly one other point.
+fn ring_rotate(position: (i32, i32), clockwise: bool) -> (i32, i32) {
+    let (mut x, mut y) = position;
+    let ring = x.abs().max(y.abs());
+    let mut remaining = ring;
+    while remaining > 0 {
+        // walk along the edge of the ring until reaching a corner
+        let (step, distance) = match clockwise {
+            true if y == ring && x < ring => ((1, 0), ring - x),
+            true if x == ring && y > -ring => ((0, -1), y + ring),
+            true if y == -ring && x > -ring => ((-1, 0), x + ring),
+            true => ((0, 1), ring - y),
+            false if y == ring && x > -ring => ((-1, 0), x + ring),
+            false if x == -ring && y > -ring => ((0, -1), y + ring),
+            false if y == -ring && x < ring => ((1, 0), ring - x),
+            false => ((0, 1), ring - y),
+        };
+        let distance = distance.min(remaining);
+        x += step.0 * distance;
+        y += step.1 * distance;
+        remaining -= distance;
+    }
+    (x, y)
+}
+
+/// A shape moved, rotated, mirrored and scaled by a [`ShapeTransform`]
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Transformed<S> {
+    shape: S,
+    transform: ShapeTransform,
+}
+
+impl<S: Shape> Transformed<S> {
+    /// Creates a new transformed shape.
+    pub const fn new(shape: S, transform: ShapeTransform) -> Self {
+        Self { shape, transform }
+    }
+
+    /// Get the shape being transformed
+    #[inline]
+    pub const fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    /// Get the transform applied to the shape
+    #[inline]
+    pub const fn transform(&self) -> ShapeTransform {
+        self.transform
+    }
+}
+
+impl<S: Shape> Shape for Transformed<S> {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        let scale = self.transform.scale();
+        self.shape.get_count() * scale * scale
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.shape.contains(self.transform.inverse(position))
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(TransformedIter::new(
+            self.shape.boxed_iter(),
+            self.transform,
+        ))
+    }
+
+    fn bounds(&self) -> Rectangle {
+        let bounds = self.shape.bounds();
+        let scale = self.transform.scale() as i32;
+        let min = (bounds.min.0 * scale, bounds.min.1 * scale);
+        let max = (
+            bounds.max.0 * scale + scale - 1,
+            bounds.max.1 * scale + scale - 1,
+        );
+        let min = self.transform.mirror_and_quarter_turn(min);
+        let max = self.transform.mirror_and_quarter_turn(max);
+
+        let (min, max) = if self.transform.clockwise_steps() % 2 == 1 {
+            // a 45° step keeps points on their square ring
+            let ring = [min.0, min.1, max.0, max.1]
+                .into_iter()
+                .map(i32::abs)
+                .max()
+                .unwrap_or(0);
+            ((-ring, -ring), (ring, ring))
+        } else {
+            (min, max)
+        };
+
+        let offset = self.transform.offset;
+        Rectangle::new(
+            (min.0 + offset.0, min.1 + offset.1),
+            (max.0 + offset.0, max.1 + offset.1),
+        )
+    }
+}
+
+impl<S: Shape + ShapeIter> ShapeIter for Transformed<S> {
+    type Iterator = TransformedIter<S::Iterator>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        TransformedIter::new(self.shape.iter(), self.transform)
+    }
+}
+
+impl<S: Shape + 'static> From<Transformed<S>> for BoxedShape {
+    fn from(value: Transformed<S>) -> Self {
+        Box::new(value)
+    }
+}
+
+/// An iterator over the points of a transformed shape.
+///
+/// Each point of the shape is yielded as its whole scaled block before moving on.
+#[derive(Debug, Clone)]
+pub struct TransformedIter<I> {
+    iter: I,
+    transform: ShapeTransform,
+
+    current: Option<(i32, i32)>,
+    sub_position: (i32, i32),
+}
+
+impl<I: Iterator<Item = (i32, i32)>> TransformedIter<I> {
+    /// Creates a new iterator transforming every point of `iter`.
+    pub fn new(mut iter: I, transform: ShapeTransform) -> Self {
+        Self {
+            current: iter.next(),
+            iter,
+            transform,
+            sub_position: (0, 0),
+        }
+    }
+}
+
+impl<I: Iterator<Item = (i32, i32)>> Iterator for TransformedIter<I> {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        let p = self.transform.apply_scaled(current, self.sub_position);
+
+        let scale = self.transform.scale() as i32;
+        self.sub_position.0 += 1;
+        if self.sub_position.0 >= scale {
+            self.sub_position.0 = 0;
+            self.sub_position.1 += 1;
+            if self.sub_position.1 >= scale {
+                self.sub_position.1 = 0;
+                self.current = self.iter.next();
+            }
+        }
+
+        Some(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{ShapeTransform, Transformed};
+    use crate::{
+        direction::Direction,
+        grid_shapes::{Line, Rectangle, Shape, ShapeIter, Triangle},
+    };
+
+    #[test]
+    fn test_transform_rotation() {
+        for direction in Direction::iter_cardinal_ordinal() {
+            let transform = ShapeTransform::IDENTITY.with_rotation(direction);
+            assert_eq!(transform.apply((0, 1)), direction.coord());
+            assert_eq!(transform.apply((0, 4)), {
+                let (x, y) = direction.coord();
+                (x * 4, y * 4)
+            });
+
+            let turned = ShapeTransform::IDENTITY.with_rotation(direction.right45());
+            assert_eq!(turned.apply((0, 1)), transform.apply((1, 1)));
+        }
+    }
+
+    #[test]
+    fn test_transform_inverse() {
+        for direction in Direction::iter_cardinal_ordinal() {
+            for scale in 1..=3 {
+                let transform = ShapeTransform::from_offset((5, -2))
+                    .with_rotation(direction)
+                    .with_mirror_x(scale == 2)
+                    .with_mirror_y(scale == 3)
+                    .with_scale(scale);
+                for position in Rectangle::new((-6, -6), (6, 6)) {
+                    assert_eq!(transform.inverse(transform.apply(position)), position);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_transformed_shape() {
+        let triangle = Triangle::new((0, 0), (5, 1), (1, 4));
+        for direction in Direction::iter_cardinal_ordinal() {
+            for scale in 0..=3 {
+                let transform = ShapeTransform::from_offset((-3, 7))
+                    .with_rotation(direction)
+                    .with_mirror_x(scale % 2 == 1)
+                    .with_scale(scale);
+                let shape = Transformed::new(triangle, transform);
+
+                let points: Vec<(i32, i32)> = shape.iter().collect();
+                let unique: HashSet<(i32, i32)> = points.iter().copied().collect();
+                assert_eq!(points.len(), unique.len());
+                assert_eq!(shape.get_count() as usize, points.len());
+                assert_eq!(
+                    shape.get_count(),
+                    triangle.get_count() * scale.max(1) * scale.max(1)
+                );
+
+                let bounds = shape.bounds();
+                assert!(points.iter().all(|p| bounds.contains(*p)));
+                for position in bounds {
+                    assert_eq!(shape.contains(position), unique.contains(&position));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_transformed_mirror() {
+        let line = Line::new((0, 0), (3, 1));
+        let mirrored = Transformed::new(line, ShapeTransform::IDENTITY.with_mirror_x(true));
+        assert_eq!(
+            mirrored.get_positions(),
+            line.iter().map(|(x, y)| (-x, y)).collect()
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::grid_shapes::{
+    iter::FilteredRectIter, BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter,
+};
+
+/// A filled triangle, including the points on its edges.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Triangle {
+    vertices: [(i32, i32); 3],
+}
+
+impl Triangle {
+    /// Creates a new triangle.
+    pub const fn new(a: (i32, i32), b: (i32, i32), c: (i32, i32)) -> Self {
+        Self {
+            vertices: [a, b, c],
+        }
+    }
+}
+
+impl Triangle {
+    /// Get the vertices of the triangle
+    #[inline]
+    pub const fn vertices(&self) -> [(i32, i32); 3] {
+        self.vertices
+    }
+}
+
+impl Shape for Triangle {
+    /// Counts the points using Pick's theorem: `points = area + boundary / 2 + 1`
+    #[inline]
+    fn get_count(&self) -> u32 {
+        let [a, b, c] = self.vertices;
+        let double_area = edge_function(a, b, c).unsigned_abs();
+        let boundary = edge_points(a, b) + edge_points(b, c) + edge_points(c, a);
+        ((double_area + boundary) / 2 + 1) as u32
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        if !self.bounds().contains(position) {
+            return false;
+        }
+
+        let [a, b, c] = self.vertices;
+        let ab = edge_function(a, b, position);
+        let bc = edge_function(b, c, position);
+        let ca = edge_function(c, a, position);
+        (ab >= 0 && bc >= 0 && ca >= 0) || (ab <= 0 && bc <= 0 && ca <= 0)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.into_iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let [a, b, c] = self.vertices;
+        Rectangle::new(
+            (a.0.min(b.0).min(c.0), a.1.min(b.1).min(c.1)),
+            (a.0.max(b.0).max(c.0), a.1.max(b.1).max(c.1)),
+        )
+    }
+}
+
+impl ShapeIter for Triangle {
+    type Iterator = FilteredRectIter<Self>;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for Triangle {
+    type IntoIter = FilteredRectIter<Self>;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        FilteredRectIter::new(self)
+    }
+}
+
+impl From<Triangle> for BoxedShape {
+    fn from(value: Triangle) -> Self {
+        Box::new(value)
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `position`. Positive when `position` is to
+/// the left of `a` -> `b`, zero when it is on the line.
+#[inline]
+pub(crate) fn edge_function(a: (i32, i32), b: (i32, i32), position: (i32, i32)) -> i64 {
+    (b.0 - a.0) as i64 * (position.1 - a.1) as i64 - (b.1 - a.1) as i64 * (position.0 - a.0) as i64
+}
+
+/// The number of points on the segment `a` -> `b`, excluding `b`.
+#[inline]
+fn edge_points(a: (i32, i32), b: (i32, i32)) -> u64 {
+    let (mut x, mut y) = ((b.0 - a.0).unsigned_abs(), (b.1 - a.1).unsigned_abs());
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    x as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Triangle;
+    use crate::grid_shapes::{Shape, ShapeIter};
+
+    #[test]
+    fn test_triangle_count() {
+        assert_eq!(Triangle::new((0, 0), (0, 0), (0, 0)).iter().count(), 1);
+        assert_eq!(Triangle::new((0, 0), (4, 0), (0, 4)).iter().count(), 15);
+        assert_eq!(Triangle::new((0, 0), (2, 2), (4, 4)).iter().count(), 5);
+
+        let corners = [(0, 0), (7, 1), (-3, 5), (2, -6), (4, 4), (-5, -2)];
+        for a in corners {
+            for b in corners {
+                for c in corners {
+                    let triangle = Triangle::new(a, b, c);
+                    assert_eq!(
+                        triangle.iter().count() as u32,
+                        triangle.get_count(),
+                        "{triangle:?}"
+                    );
+                    assert!(triangle.contains(a) && triangle.contains(b) && triangle.contains(c));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::hex::{HexDirection, HexOrientation};
+
+/// A hex in axial coordinates.
+///
+/// The cube coordinate `s` is implied by `q + r + s == 0`. Hex shapes implement
+/// [`Shape`](crate::grid_shapes::Shape) over `(q, r)` positions.
+#[derive(Serialize, Deserialize, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hex {
+    /// The `q` axis, towards [`HexDirection`] `0`.
+    pub q: i32,
+    /// The `r` axis, towards [`HexDirection`] `1`.
+    pub r: i32,
+}
+
+// Constructors
+impl Hex {
+    /// The hex at `(0, 0)`
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// Creates a new hex from axial coordinates.
+    #[inline]
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// Creates a new hex from cube coordinates.
+    ///
+    /// NOTE: `s` is ignored, it must be `-q - r`.
+    #[inline]
+    pub const fn from_cube(cube: (i32, i32, i32)) -> Self {
+        debug_assert!(cube.0 + cube.1 + cube.2 == 0);
+        Self::new(cube.0, cube.1)
+    }
+
+    /// Creates a new hex from offset `(column, row)` coordinates.
+    ///
+    /// `PointyTop` shifts odd rows, `FlatTop` shifts odd columns.
+    #[inline]
+    pub const fn from_offset(offset: (i32, i32), orientation: HexOrientation) -> Self {
+        let (column, row) = offset;
+        match orientation {
+            HexOrientation::PointyTop => Self::new(column - (row - (row & 1)) / 2, row),
+            HexOrientation::FlatTop => Self::new(column, row - (column - (column & 1)) / 2),
+        }
+    }
+
+    /// Rounds fractional axial coordinates to the nearest hex.
+    pub fn round(q: f32, r: f32) -> Self {
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        Self::new(rq as i32, rr as i32)
+    }
+}
+
+impl Hex {
+    /// Get the implied cube coordinate `s`.
+    #[inline]
+    pub const fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// Get the cube coordinates `(q, r, s)`.
+    #[inline]
+    pub const fn to_cube(&self) -> (i32, i32, i32) {
+        (self.q, self.r, self.s())
+    }
+
+    /// Get the offset `(column, row)` coordinates.
+    ///
+    /// `PointyTop` shifts odd rows, `FlatTop` shifts odd columns.
+    #[inline]
+    pub const fn to_offset(&self, orientation: HexOrientation) -> (i32, i32) {
+        match orientation {
+            HexOrientation::PointyTop => (self.q + (self.r - (self.r & 1)) / 2, self.r),
+            HexOrientation::FlatTop => (self.q, self.r + (self.q - (self.q & 1)) / 2),
+        }
+    }
+
+    /// Get the number of steps to `other`.
+    #[inline]
+    pub const fn distance(&self, other: Self) -> u32 {
+        let (q, r, s) = Self::new(other.q - self.q, other.r - self.r).to_cube();
+        (q.unsigned_abs() + r.unsigned_abs() + s.unsigned_abs()) / 2
+    }
+
+    /// Get the number of steps from `(0, 0)`.
+    #[inline]
+    pub const fn length(&self) -> u32 {
+        self.distance(Self::ZERO)
+    }
+
+    /// Get the neighbouring hex towards `direction`.
+    #[inline]
+    pub const fn neighbor(&self, direction: HexDirection) -> Self {
+        let offset = direction.coord();
+        Self::new(self.q + offset.q, self.r + offset.r)
+    }
+
+    /// Rotates the hex counter clockwise around `(0, 0)` by `60°`.
+    #[inline]
+    pub const fn left60(&self) -> Self {
+        Self::new(-self.r, -self.s())
+    }
+
+    /// Rotates the hex clockwise around `(0, 0)` by `60°`.
+    #[inline]
+    pub const fn right60(&self) -> Self {
+        Self::new(-self.s(), -self.q)
+    }
+
+    /// Rotates the hex counter clockwise around `center` by `60°` `steps` times.
+    pub fn rotate_around(&self, center: Self, steps: i32) -> Self {
+        let mut offset = *self - center;
+        for _ in 0..steps.rem_euclid(6) {
+            offset = offset.left60();
+        }
+        center + offset
+    }
+}
+
+impl From<(i32, i32)> for Hex {
+    #[inline]
+    fn from(value: (i32, i32)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl From<Hex> for (i32, i32) {
+    #[inline]
+    fn from(value: Hex) -> Self {
+        (value.q, value.r)
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Hex {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl SubAssign for Hex {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hex {{q: {}, r: {}}}", self.q, self.r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hex;
+    use crate::{
+        grid_shapes::Rectangle,
+        hex::{HexDirection, HexOrientation},
+    };
+
+    #[test]
+    fn test_hex_distance() {
+        let a = Hex::new(1, -3);
+        assert_eq!(a.distance(a), 0);
+        for direction in HexDirection::iter() {
+            assert_eq!(a.distance(a.neighbor(direction)), 1);
+            assert_eq!(a.distance(a + direction.coord() * 4), 4);
+        }
+        assert_eq!(Hex::new(3, -1).length(), 3);
+        assert_eq!(Hex::new(2, 2).length(), 4);
+    }
+
+    #[test]
+    fn test_hex_offset() {
+        for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            for offset in Rectangle::new((-5, -5), (5, 5)) {
+                let hex = Hex::from_offset(offset, orientation);
+                assert_eq!(hex.to_offset(orientation), offset);
+            }
+        }
+        assert_eq!(Hex::new(0, 1).to_offset(HexOrientation::PointyTop), (0, 1));
+        assert_eq!(Hex::new(-1, 2).to_offset(HexOrientation::PointyTop), (0, 2));
+        assert_eq!(Hex::new(1, 0).to_offset(HexOrientation::FlatTop), (1, 0));
+        assert_eq!(Hex::new(2, -1).to_offset(HexOrientation::FlatTop), (2, 0));
+    }
+
+    #[test]
+    fn test_hex_rotation() {
+        let hex = Hex::new(2, -1);
+        assert_eq!(hex.left60().right60(), hex);
+        assert_eq!(hex.rotate_around(Hex::ZERO, 6), hex);
+        assert_eq!(hex.rotate_around(Hex::ZERO, 3), -hex);
+        assert_eq!(hex.rotate_around(Hex::ZERO, -1), hex.right60());
+
+        for direction in HexDirection::iter() {
+            assert_eq!(direction.coord().left60(), direction.left60().coord());
+        }
+
+        let center = Hex::new(5, 5);
+        assert_eq!(
+            (center + hex).rotate_around(center, 2),
+            center + hex.left60().left60()
+        );
+        assert_eq!(Hex::round(1.6, -0.7), Hex::new(2, -1));
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt::Display;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::hex::{Hex, HexOrientation};
+
+/// The axial offset of each [`HexDirection`], counter clockwise.
+const HEX_DIRECTION_TABLE: [Hex; 6] = [
+    Hex::new(1, 0),
+    Hex::new(0, 1),
+    Hex::new(-1, 1),
+    Hex::new(-1, 0),
+    Hex::new(0, -1),
+    Hex::new(1, -1),
+];
+
+/// Names the [`HexDirection`]s of a `PointyTop` layout
+pub struct PointyHexDirection;
+impl PointyHexDirection {
+    /// Returns a [`HexDirection`] representing `EAST`
+    pub const EAST: HexDirection = HexDirection(0);
+    /// Returns a [`HexDirection`] representing `NORTH_EAST`
+    pub const NORTH_EAST: HexDirection = HexDirection(1);
+    /// Returns a [`HexDirection`] representing `NORTH_WEST`
+    pub const NORTH_WEST: HexDirection = HexDirection(2);
+    /// Returns a [`HexDirection`] representing `WEST`
+    pub const WEST: HexDirection = HexDirection(3);
+    /// Returns a [`HexDirection`] representing `SOUTH_WEST`
+    pub const SOUTH_WEST: HexDirection = HexDirection(4);
+    /// Returns a [`HexDirection`] representing `SOUTH_EAST`
+    pub const SOUTH_EAST: HexDirection = HexDirection(5);
+}
+
+/// Names the [`HexDirection`]s of a `FlatTop` layout
+pub struct FlatHexDirection;
+impl FlatHexDirection {
+    /// Returns a [`HexDirection`] representing `NORTH_EAST`
+    pub const NORTH_EAST: HexDirection = HexDirection(0);
+    /// Returns a [`HexDirection`] representing `NORTH`
+    pub const NORTH: HexDirection = HexDirection(1);
+    /// Returns a [`HexDirection`] representing `NORTH_WEST`
+    pub const NORTH_WEST: HexDirection = HexDirection(2);
+    /// Returns a [`HexDirection`] representing `SOUTH_WEST`
+    pub const SOUTH_WEST: HexDirection = HexDirection(3);
+    /// Returns a [`HexDirection`] representing `SOUTH`
+    pub const SOUTH: HexDirection = HexDirection(4);
+    /// Returns a [`HexDirection`] representing `SOUTH_EAST`
+    pub const SOUTH_EAST: HexDirection = HexDirection(5);
+}
+
+/// One of the six directions to a neighbouring hex, numbered `0..6` counter clockwise.
+///
+/// The axial offsets are the same for both layouts, see [`PointyHexDirection`] and
+/// [`FlatHexDirection`] for their names.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexDirection(u8);
+
+impl HexDirection {
+    /// Retrieves the [`HexDirection`] numbered `index`, wrapping every `6`.
+    #[inline]
+    pub const fn from_index(index: i32) -> Self {
+        Self(index.rem_euclid(6) as u8)
+    }
+
+    /// Get the number of the [`HexDirection`], `0..6` counter clockwise.
+    #[inline]
+    pub const fn index(self) -> u8 {
+        self.0
+    }
+
+    /// Retrieves the axial offset of the [`HexDirection`].
+    #[inline]
+    pub const fn coord(self) -> Hex {
+        HEX_DIRECTION_TABLE[self.0 as usize]
+    }
+
+    /// Retrieves the [`HexDirection`] of a neighbouring axial offset.
+    pub fn from_coord(coord: Hex) -> Option<Self> {
+        HEX_DIRECTION_TABLE
+            .iter()
+            .position(|&offset| offset == coord)
+            .map(|index| Self(index as u8))
+    }
+
+    /// Get the angle of the [`HexDirection`] in radians, counter clockwise from `EAST`.
+    #[inline]
+    pub fn angle(self, orientation: HexOrientation) -> f32 {
+        orientation.start_angle() + self.0 as f32 * std::f32::consts::FRAC_PI_3
+    }
+}
+
+// Rotations
+impl HexDirection {
+    /// Rotates a [`HexDirection`] counter clockwise by one step.
+    #[inline]
+    pub const fn left60(self) -> Self {
+        Self::from_index(self.0 as i32 + 1)
+    }
+
+    /// Rotates a [`HexDirection`] counter clockwise by two steps.
+    #[inline]
+    pub const fn left120(self) -> Self {
+        Self::from_index(self.0 as i32 + 2)
+    }
+
+    /// Rotates a [`HexDirection`] by three steps.
+    #[inline]
+    pub const fn opposite(self) -> Self {
+        Self::from_index(self.0 as i32 + 3)
+    }
+
+    /// Rotates a [`HexDirection`] clockwise by two steps.
+    #[inline]
+    pub const fn right120(self) -> Self {
+        Self::from_index(self.0 as i32 - 2)
+    }
+
+    /// Rotates a [`HexDirection`] clockwise by one step.
+    #[inline]
+    pub const fn right60(self) -> Self {
+        Self::from_index(self.0 as i32 - 1)
+    }
+}
+
+// Iterators
+impl HexDirection {
+    /// Returns an iterator over every [`HexDirection`], counter clockwise from `0`.
+    #[inline]
+    pub const fn iter() -> HexDirectionIter {
+        HexDirectionIter { current: 0 }
+    }
+}
+
+/// An iterator over every [`HexDirection`], counter clockwise.
+#[derive(Debug, Clone)]
+pub struct HexDirectionIter {
+    current: u8,
+}
+
+impl Iterator for HexDirectionIter {
+    type Item = HexDirection;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= 6 {
+            None
+        } else {
+            self.current += 1;
+            Some(HexDirection(self.current - 1))
+        }
+    }
+}
+
+impl Display for HexDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HexDirection({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FlatHexDirection, HexDirection, PointyHexDirection};
+    use crate::hex::{Hex, HexLayout, HexOrientation};
+
+    #[test]
+    fn test_hex_direction_rotation() {
+        let directions: Vec<HexDirection> = HexDirection::iter().collect();
+        assert_eq!(directions.len(), 6);
+        for direction in directions {
+            assert_eq!(direction.left60().right60(), direction);
+            assert_eq!(direction.left120(), direction.left60().left60());
+            assert_eq!(direction.right120(), direction.right60().right60());
+            assert_eq!(direction.opposite().coord(), -direction.coord());
+            assert_eq!(HexDirection::from_coord(direction.coord()), Some(direction));
+        }
+        assert_eq!(HexDirection::from_coord(Hex::new(1, 1)), None);
+        assert_eq!(
+            PointyHexDirection::EAST.left60(),
+            PointyHexDirection::NORTH_EAST
+        );
+        assert_eq!(
+            FlatHexDirection::SOUTH_EAST.left60(),
+            FlatHexDirection::NORTH_EAST
+        );
+    }
+
+    #[test]
+    fn test_hex_direction_angle() {
+        for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            let layout = HexLayout::new(orientation, 1.0);
+            for direction in HexDirection::iter() {
+                let (x, y) = layout.hex_to_world(direction.coord());
+                let angle = direction.angle(orientation);
+                assert!((y.atan2(x) - angle).sin().abs() < 1e-5, "{direction}");
+            }
+        }
+        assert_eq!(
+            HexDirection::from_coord(Hex::new(0, 1)),
+            Some(FlatHexDirection::NORTH)
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::ops::{Index, IndexMut};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    grid::Grid,
+    hex::{Hex, HexDirection, HexOrientation},
+};
+
+/// A [`Grid`] of hexes, stored by their offset `(column, row)` coordinates.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone)]
+pub struct HexGrid<T> {
+    orientation: HexOrientation,
+    grid: Grid<T>,
+}
+
+// Constructors
+impl<T> HexGrid<T> {
+    /// Creates a new hex grid from a grid of offset coordinates.
+    #[inline]
+    pub const fn from_grid(orientation: HexOrientation, grid: Grid<T>) -> Self {
+        Self { orientation, grid }
+    }
+
+    /// Creates a new hex grid with every hex set to `value`.
+    #[inline]
+    pub fn new_clone(orientation: HexOrientation, size: (u32, u32), value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_grid(orientation, Grid::new_clone(size, value))
+    }
+
+    /// Creates a new hex grid with every hex set to `T::default()`.
+    #[inline]
+    pub fn new_default(orientation: HexOrientation, size: (u32, u32)) -> Self
+    where
+        T: Default + Clone,
+    {
+        Self::from_grid(orientation, Grid::new_default(size))
+    }
+
+    /// Creates a new hex grid with every hex set by `f`.
+    pub fn new_fn(
+        orientation: HexOrientation,
+        size: (u32, u32),
+        mut f: impl FnMut(Hex) -> T,
+    ) -> Self {
+        Self::from_grid(
+            orientation,
+            Grid::new_fn(size, |_, offset| f(Hex::from_offset(offset, orientation))),
+        )
+    }
+}
+
+impl<T> HexGrid<T> {
+    /// Get the orientation of the hexes.
+    #[inline]
+    pub const fn orientation(&self) -> HexOrientation {
+        self.orientation
+    }
+
+    /// Get the underlying grid of offset coordinates.
+    #[inline]
+    pub const fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// Get the underlying grid of offset coordinates mutably.
+    #[inline]
+    pub fn grid_mut(&mut self) -> &mut Grid<T> {
+        &mut self.grid
+    }
+
+    /// Get the offset coordinates of `hex`.
+    #[inline]
+    pub const fn to_offset(&self, hex: Hex) -> (i32, i32) {
+        hex.to_offset(self.orientation)
+    }
+
+    /// Get the hex at offset coordinates.
+    #[inline]
+    pub const fn from_offset(&self, offset: (i32, i32)) -> Hex {
+        Hex::from_offset(offset, self.orientation)
+    }
+
+    /// Check if `hex` is stored in the grid.
+    #[inline]
+    pub fn in_bounds(&self, hex: Hex) -> bool {
+        self.grid.get(self.to_offset(hex)).is_some()
+    }
+
+    /// Get the value of `hex`.
+    #[inline]
+    pub fn get(&self, hex: Hex) -> Option<&T> {
+        self.grid.get(self.to_offset(hex))
+    }
+
+    /// Get the value of `hex` mutably.
+    #[inline]
+    pub fn get_mut(&mut self, hex: Hex) -> Option<&mut T> {
+        let offset = self.to_offset(hex);
+        self.grid.get_mut(offset)
+    }
+
+    /// Get the neighbours of `hex` which are stored in the grid, counter clockwise from
+    /// [`HexDirection`] `0`.
+    pub fn neighbors(&self, hex: Hex) -> impl Iterator<Item = Hex> + '_ {
+        HexDirection::iter()
+            .map(move |direction| hex.neighbor(direction))
+            .filter(|&neighbor| self.in_bounds(neighbor))
+    }
+
+    /// Get every hex and value in the grid, in the row-major order of the offset coordinates.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Hex, &T)> {
+        let orientation = self.orientation;
+        self.grid
+            .enumerate()
+            .map(move |(offset, value)| (Hex::from_offset(offset, orientation), value))
+    }
+}
+
+impl<T> Index<Hex> for HexGrid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, hex: Hex) -> &Self::Output {
+        &self.grid[self.to_offset(hex)]
+    }
+}
+
+impl<T> IndexMut<Hex> for HexGrid<T> {
+    #[inline]
+    fn index_mut(&mut self, hex: Hex) -> &mut Self::Output {
+        let offset = self.to_offset(hex);
+        &mut self.grid[offset]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HexGrid;
+    use crate::hex::{Hex, HexOrientation};
+
+    #[test]
+    fn test_hex_grid() {
+        for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            let mut grid = HexGrid::new_fn(orientation, (6, 5), |hex| hex);
+            for (hex, &value) in grid.enumerate() {
+                assert_eq!(hex, value);
+                assert_eq!(grid[hex], hex);
+            }
+
+            let corner = grid.from_offset((0, 0));
+            let inner = grid.from_offset((2, 2));
+            assert_eq!(grid.neighbors(inner).count(), 6);
+            assert!(grid.neighbors(corner).count() < 6);
+            assert!(grid
+                .neighbors(inner)
+                .all(|neighbor| neighbor.distance(inner) == 1));
+
+            grid[inner] = Hex::ZERO;
+            assert_eq!(grid.get(inner), Some(&Hex::ZERO));
+            assert_eq!(grid.get(grid.from_offset((6, 0))), None);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::f32::consts::FRAC_PI_6;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::hex::Hex;
+
+const SQRT_3: f32 = 1.732_050_8;
+
+/// Which way up hexes are drawn.
+#[derive(Serialize, Deserialize, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexOrientation {
+    /// A corner points `NORTH`, hexes line up in rows.
+    #[default]
+    PointyTop,
+    /// An edge faces `NORTH`, hexes line up in columns.
+    FlatTop,
+}
+
+impl HexOrientation {
+    /// Get the angle of [`HexDirection`](crate::hex::HexDirection) `0` in radians, counter
+    /// clockwise from `EAST`.
+    #[inline]
+    pub const fn start_angle(self) -> f32 {
+        match self {
+            Self::PointyTop => 0.0,
+            Self::FlatTop => FRAC_PI_6,
+        }
+    }
+}
+
+/// Converts between hexes and world positions, `Y` points `NORTH`.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct HexLayout {
+    /// Which way up hexes are drawn.
+    pub orientation: HexOrientation,
+    /// The distance from the center of a hex to its corners.
+    pub size: f32,
+    /// The world position of `Hex::ZERO`.
+    pub origin: (f32, f32),
+}
+
+impl HexLayout {
+    /// Creates a new layout with `Hex::ZERO` at the world origin.
+    #[inline]
+    pub const fn new(orientation: HexOrientation, size: f32) -> Self {
+        Self {
+            orientation,
+            size,
+            origin: (0.0, 0.0),
+        }
+    }
+
+    /// Sets the world position of `Hex::ZERO`.
+    #[inline]
+    pub const fn with_origin(mut self, origin: (f32, f32)) -> Self {
+        self.origin = origin;
+        self
+    }
+}
+
+impl HexLayout {
+    /// Get the world position of the center of `hex`.
+    pub fn hex_to_world(&self, hex: Hex) -> (f32, f32) {
+        let (q, r) = (hex.q as f32, hex.r as f32);
+        let (x, y) = match self.orientation {
+            HexOrientation::PointyTop => (SQRT_3 * q + SQRT_3 / 2.0 * r, 1.5 * r),
+            HexOrientation::FlatTop => (1.5 * q, SQRT_3 / 2.0 * q + SQRT_3 * r),
+        };
+        (self.origin.0 + x * self.size, self.origin.1 + y * self.size)
+    }
+
+    /// Get the hex containing a world position.
+    pub fn world_to_hex(&self, position: (f32, f32)) -> Hex {
+        let x = (position.0 - self.origin.0) / self.size;
+        let y = (position.1 - self.origin.1) / self.size;
+        let (q, r) = match self.orientation {
+            HexOrientation::PointyTop => (SQRT_3 / 3.0 * x - y / 3.0, 2.0 / 3.0 * y),
+            HexOrientation::FlatTop => (2.0 / 3.0 * x, -x / 3.0 + SQRT_3 / 3.0 * y),
+        };
+        Hex::round(q, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HexLayout, HexOrientation};
+    use crate::{grid_shapes::Rectangle, hex::Hex};
+
+    #[test]
+    fn test_hex_layout() {
+        for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            let layout = HexLayout::new(orientation, 16.0).with_origin((-40.0, 12.5));
+            for (q, r) in Rectangle::new((-6, -6), (6, 6)) {
+                let hex = Hex::new(q, r);
+                let (x, y) = layout.hex_to_world(hex);
+                assert_eq!(layout.world_to_hex((x, y)), hex);
+                // anywhere within the inner radius
+                assert_eq!(layout.world_to_hex((x + 13.0, y)), hex);
+                assert_eq!(layout.world_to_hex((x, y - 13.0)), hex);
+            }
+        }
+
+        let pointy = HexLayout::new(HexOrientation::PointyTop, 1.0);
+        assert_eq!(pointy.hex_to_world(Hex::new(0, 1)).1, 1.5);
+        let flat = HexLayout::new(HexOrientation::FlatTop, 1.0);
+        assert_eq!(flat.hex_to_world(Hex::new(1, 0)).0, 1.5);
+    }
+}
@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    grid_shapes::{BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter},
+    hex::Hex,
+};
+
+/// A line of hexes from `start` to `end`, as `(q, r)` positions.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HexLine {
+    start: Hex,
+    end: Hex,
+}
+
+impl HexLine {
+    /// Creates a new hex line.
+    #[inline]
+    pub const fn new(start: Hex, end: Hex) -> Self {
+        Self { start, end }
+    }
+}
+
+impl HexLine {
+    /// Get the first hex of the line
+    #[inline]
+    pub const fn start(&self) -> Hex {
+        self.start
+    }
+
+    /// Get the last hex of the line
+    #[inline]
+    pub const fn end(&self) -> Hex {
+        self.end
+    }
+}
+
+impl Shape for HexLine {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.start.distance(self.end) + 1
+    }
+
+    /// Solves for the step along the line closest to `position`.
+    fn contains(&self, position: (i32, i32)) -> bool {
+        let position = Hex::from(position);
+        let steps = self.start.distance(self.end);
+        let step = self.start.distance(position);
+        step <= steps && self.iter().point_at(step) == position
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.start.into(), self.end.into())
+    }
+}
+
+impl ShapeIter for HexLine {
+    type Iterator = HexLineIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for HexLine {
+    type IntoIter = HexLineIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        HexLineIter {
+            start: self.start,
+            end: self.end,
+            steps: self.start.distance(self.end),
+            step: 0,
+        }
+    }
+}
+
+impl From<HexLine> for BoxedShape {
+    fn from(value: HexLine) -> Self {
+        Box::new(value)
+    }
+}
+
+/// An iterator over a [`HexLine`], rounding evenly spaced points between the centers.
+#[derive(Debug, Clone)]
+pub struct HexLineIter {
+    start: Hex,
+    end: Hex,
+    steps: u32,
+    step: u32,
+}
+
+impl HexLineIter {
+    /// Get the hex `step` steps along the line.
+    pub(crate) fn point_at(&self, step: u32) -> Hex {
+        if self.steps == 0 {
+            return self.start;
+        }
+        // nudge off the edges between hexes so ties always round the same way
+        let t = step as f64 / self.steps as f64;
+        let q = self.start.q as f64 + 1e-6 + (self.end.q - self.start.q) as f64 * t;
+        let r = self.start.r as f64 + 1e-6 + (self.end.r - self.start.r) as f64 * t;
+        Hex::round(q as f32, r as f32)
+    }
+}
+
+impl Iterator for HexLineIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step > self.steps {
+            None
+        } else {
+            self.step += 1;
+            Some(self.point_at(self.step - 1).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::HexLine;
+    use crate::{
+        grid_shapes::{Shape, ShapeIter},
+        hex::{Hex, HexRange},
+    };
+
+    #[test]
+    fn test_hex_line() {
+        let start = Hex::new(1, -2);
+        for end in HexRange::new(start, 7).iter() {
+            let line = HexLine::new(start, end.into());
+            let points: Vec<(i32, i32)> = line.iter().collect();
+            let unique: HashSet<(i32, i32)> = points.iter().copied().collect();
+            assert_eq!(points.len(), unique.len());
+            assert_eq!(points.len() as u32, line.get_count());
+            assert_eq!((points[0], points[points.len() - 1]), (start.into(), end));
+            for pair in points.windows(2) {
+                assert_eq!(Hex::from(pair[0]).distance(pair[1].into()), 1);
+            }
+            for position in line.bounds().expand(1) {
+                assert_eq!(line.contains(position), unique.contains(&position));
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    grid_shapes::{BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter},
+    hex::Hex,
+};
+
+/// Every hex within `radius` steps of `center`, as `(q, r)` positions.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HexRange {
+    center: Hex,
+    radius: u32,
+}
+
+impl HexRange {
+    /// Creates a new hex range.
+    #[inline]
+    pub const fn new(center: Hex, radius: u32) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl HexRange {
+    /// Get the center of the range
+    #[inline]
+    pub const fn center(&self) -> Hex {
+        self.center
+    }
+
+    /// Get the radius of the range
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+}
+
+impl Shape for HexRange {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        3 * self.radius * (self.radius + 1) + 1
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.center.distance(position.into()) <= self.radius
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let radius = self.radius as i32;
+        Rectangle::new(
+            (self.center.q - radius, self.center.r - radius),
+            (self.center.q + radius, self.center.r + radius),
+        )
+    }
+}
+
+impl ShapeIter for HexRange {
+    type Iterator = HexRangeIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for HexRange {
+    type IntoIter = HexRangeIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let radius = self.radius as i32;
+        HexRangeIter {
+            center: self.center,
+            radius,
+            // the lowest row starts at `q == 0`
+            offset: Hex::new(0, -radius),
+        }
+    }
+}
+
+impl From<HexRange> for BoxedShape {
+    fn from(value: HexRange) -> Self {
+        Box::new(value)
+    }
+}
+
+/// An iterator over a [`HexRange`].
+///
+/// Points are yielded row by row along `q`, from the lowest `r` up.
+#[derive(Debug, Clone)]
+pub struct HexRangeIter {
+    center: Hex,
+    radius: i32,
+    offset: Hex,
+}
+
+impl Iterator for HexRangeIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset.r > self.radius {
+            return None;
+        }
+        let current = self.center + self.offset;
+        self.offset.q += 1;
+        if self.offset.q > self.radius.min(self.radius - self.offset.r) {
+            self.offset.r += 1;
+            self.offset.q = (-self.radius).max(-self.radius - self.offset.r);
+        }
+        Some(current.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::HexRange;
+    use crate::{
+        grid_shapes::{Shape, ShapeIter},
+        hex::Hex,
+    };
+
+    #[test]
+    fn test_hex_range() {
+        for radius in 0..6 {
+            let range = HexRange::new(Hex::new(2, -3), radius);
+            let points: Vec<(i32, i32)> = range.iter().collect();
+            let unique: HashSet<(i32, i32)> = points.iter().copied().collect();
+            assert_eq!(points.len(), unique.len());
+            assert_eq!(points.len() as u32, range.get_count());
+            for position in range.bounds().expand(1) {
+                assert_eq!(range.contains(position), unique.contains(&position));
+            }
+        }
+    }
+}
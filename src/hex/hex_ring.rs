@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    grid_shapes::{BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter},
+    hex::{Hex, HexDirection, HexRange},
+};
+
+/// Every hex exactly `radius` steps from `center`, as `(q, r)` positions.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HexRing {
+    center: Hex,
+    radius: u32,
+}
+
+impl HexRing {
+    /// Creates a new hex ring.
+    #[inline]
+    pub const fn new(center: Hex, radius: u32) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl HexRing {
+    /// Get the center of the ring
+    #[inline]
+    pub const fn center(&self) -> Hex {
+        self.center
+    }
+
+    /// Get the radius of the ring
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+}
+
+impl Shape for HexRing {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        (6 * self.radius).max(1)
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.center.distance(position.into()) == self.radius
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        HexRange::new(self.center, self.radius).bounds()
+    }
+}
+
+impl ShapeIter for HexRing {
+    type Iterator = HexRingIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for HexRing {
+    type IntoIter = HexRingIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        HexRingIter::new(self.center, self.radius)
+    }
+}
+
+impl From<HexRing> for BoxedShape {
+    fn from(value: HexRing) -> Self {
+        Box::new(value)
+    }
+}
+
+/// An iterator over a [`HexRing`].
+///
+/// Starts at the corner towards [`HexDirection`] `4` and walks the sides counter clockwise.
+#[derive(Debug, Clone)]
+pub struct HexRingIter {
+    current: Hex,
+    radius: u32,
+    side: u8,
+    step: u32,
+}
+
+impl HexRingIter {
+    /// Creates a new hex ring iterator.
+    pub fn new(center: Hex, radius: u32) -> Self {
+        Self {
+            current: center + HexDirection::from_index(4).coord() * radius as i32,
+            radius,
+            side: 0,
+            step: 0,
+        }
+    }
+}
+
+impl Iterator for HexRingIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.side >= 6 {
+            return None;
+        }
+        let current = self.current;
+        if self.radius == 0 {
+            self.side = 6;
+            return Some(current.into());
+        }
+
+        self.current = current.neighbor(HexDirection::from_index(self.side as i32));
+        self.step += 1;
+        if self.step == self.radius {
+            self.step = 0;
+            self.side += 1;
+        }
+        Some(current.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::HexRing;
+    use crate::{
+        grid_shapes::{Shape, ShapeIter},
+        hex::Hex,
+    };
+
+    #[test]
+    fn test_hex_ring() {
+        for radius in 0..6 {
+            let ring = HexRing::new(Hex::new(-1, 4), radius);
+            let points: Vec<(i32, i32)> = ring.iter().collect();
+            let unique: HashSet<(i32, i32)> = points.iter().copied().collect();
+            assert_eq!(points.len(), unique.len());
+            assert_eq!(points.len() as u32, ring.get_count());
+            for position in ring.bounds().expand(1) {
+                assert_eq!(ring.contains(position), unique.contains(&position));
+            }
+
+            // walks from neighbour to neighbour
+            for pair in points.windows(2) {
+                assert_eq!(Hex::from(pair[0]).distance(pair[1].into()), 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    grid_shapes::{BoxedShape, BoxedShapeIter, Rectangle, Shape, ShapeIter},
+    hex::{Hex, HexRange, HexRingIter},
+};
+
+/// The hexes of a [`HexRange`], iterated from `center` outwards one ring at a time.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HexSpiral {
+    center: Hex,
+    radius: u32,
+}
+
+impl HexSpiral {
+    /// Creates a new hex spiral.
+    #[inline]
+    pub const fn new(center: Hex, radius: u32) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl HexSpiral {
+    /// Get the center of the spiral
+    #[inline]
+    pub const fn center(&self) -> Hex {
+        self.center
+    }
+
+    /// Get the radius of the outer ring
+    #[inline]
+    pub const fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// Get the range covered by the spiral
+    #[inline]
+    pub const fn as_range(&self) -> HexRange {
+        HexRange::new(self.center, self.radius)
+    }
+}
+
+impl Shape for HexSpiral {
+    #[inline]
+    fn get_count(&self) -> u32 {
+        self.as_range().get_count()
+    }
+
+    #[inline]
+    fn contains(&self, position: (i32, i32)) -> bool {
+        self.as_range().contains(position)
+    }
+
+    #[inline]
+    fn boxed_iter(&self) -> BoxedShapeIter<'_> {
+        Box::new(self.iter())
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.as_range().bounds()
+    }
+}
+
+impl ShapeIter for HexSpiral {
+    type Iterator = HexSpiralIter;
+
+    #[inline]
+    fn iter(&self) -> Self::Iterator {
+        self.into_iter()
+    }
+}
+
+impl IntoIterator for HexSpiral {
+    type IntoIter = HexSpiralIter;
+    type Item = (i32, i32);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        HexSpiralIter {
+            center: self.center,
+            radius: self.radius,
+            ring: 0,
+            ring_iter: HexRingIter::new(self.center, 0),
+        }
+    }
+}
+
+impl From<HexSpiral> for BoxedShape {
+    fn from(value: HexSpiral) -> Self {
+        Box::new(value)
+    }
+}
+
+/// An iterator over a [`HexSpiral`], each ring in the order of [`HexRingIter`].
+#[derive(Debug, Clone)]
+pub struct HexSpiralIter {
+    center: Hex,
+    radius: u32,
+    ring: u32,
+    ring_iter: HexRingIter,
+}
+
+impl Iterator for HexSpiralIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(position) = self.ring_iter.next() {
+                return Some(position);
+            }
+            if self.ring >= self.radius {
+                return None;
+            }
+            self.ring += 1;
+            self.ring_iter = HexRingIter::new(self.center, self.ring);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HexSpiral;
+    use crate::{
+        grid_shapes::{Shape, ShapeIter},
+        hex::Hex,
+    };
+
+    #[test]
+    fn test_hex_spiral() {
+        let center = Hex::new(3, 3);
+        let spiral = HexSpiral::new(center, 4);
+        let points: Vec<(i32, i32)> = spiral.iter().collect();
+        assert_eq!(points.len() as u32, spiral.get_count());
+        assert_eq!(spiral.get_positions(), spiral.as_range().get_positions());
+
+        // never moves back inwards
+        let rings: Vec<u32> = points.iter().map(|&p| center.distance(p.into())).collect();
+        assert!(rings.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(points[0], (3, 3));
+    }
+}
@@ -0,0 +1,23 @@
+mod hex;
+pub use self::hex::*;
+
+mod hex_direction;
+pub use self::hex_direction::*;
+
+mod hex_layout;
+pub use self::hex_layout::*;
+
+mod hex_grid;
+pub use self::hex_grid::*;
+
+mod hex_line;
+pub use self::hex_line::*;
+
+mod hex_range;
+pub use self::hex_range::*;
+
+mod hex_ring;
+pub use self::hex_ring::*;
+
+mod hex_spiral;
+pub use self::hex_spiral::*;
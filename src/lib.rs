@@ -1,11 +1,14 @@
 #![allow(clippy::module_inception)]
 
+pub mod autotile;
 pub mod direction;
 pub mod distance;
 pub mod generic_asset_loader;
 pub mod grid;
 pub mod grid_shapes;
+pub mod hex;
 pub mod random;
+pub mod raycast;
 
 pub mod resources;
 pub mod systems;
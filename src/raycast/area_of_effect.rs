@@ -0,0 +1,248 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    direction::Direction,
+    distance::Distance,
+    grid::Grid,
+    grid_shapes::{BoxedShape, Shape},
+    raycast::line_of_sight_symmetric,
+};
+
+/// How an [`AreaOfEffect`] spreads from its origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AreaOfEffectSpread {
+    /// Reaches every point of the shape the origin has a [`line_of_sight_symmetric`] to.
+    #[default]
+    LineOfSight,
+    /// Reaches every point of the shape connected to the origin without crossing an opaque
+    /// point, moving in the cardinal directions only.
+    FloodFill,
+}
+
+/// A point reached by an [`AreaOfEffect`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AreaOfEffectPoint {
+    /// The point reached.
+    pub position: (i32, i32),
+    /// The distance from the origin.
+    pub distance: f32,
+    /// The strength of the effect, from `1.0` at the origin to `0.0` at the falloff range.
+    pub falloff: f32,
+}
+
+/// Resolves which points of a shape an effect reaches without passing through walls.
+pub struct AreaOfEffect {
+    shape: BoxedShape,
+    origin: (i32, i32),
+    spread: AreaOfEffectSpread,
+    distance: Distance,
+    falloff_range: f32,
+}
+
+impl AreaOfEffect {
+    /// Creates a new area of effect spreading from `origin` to the points of `shape`.
+    ///
+    /// Defaults to line of sight and `Pythagoras` distance without falloff.
+    pub fn new(shape: impl Into<BoxedShape>, origin: (i32, i32)) -> Self {
+        Self {
+            shape: shape.into(),
+            origin,
+            spread: AreaOfEffectSpread::default(),
+            distance: Distance::Pythagoras,
+            falloff_range: f32::INFINITY,
+        }
+    }
+
+    /// Sets how the effect spreads from the origin.
+    pub fn with_spread(mut self, spread: AreaOfEffectSpread) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Sets how the distance from the origin is measured.
+    pub fn with_distance(mut self, distance: Distance) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    /// Sets the distance at which the falloff reaches `0.0`.
+    pub fn with_falloff_range(mut self, range: f32) -> Self {
+        self.falloff_range = range;
+        self
+    }
+}
+
+impl AreaOfEffect {
+    /// Get the shape of the area.
+    #[inline]
+    pub fn shape(&self) -> &BoxedShape {
+        &self.shape
+    }
+
+    /// Get the origin of the area.
+    #[inline]
+    pub const fn origin(&self) -> (i32, i32) {
+        self.origin
+    }
+
+    /// Resolves the points reached, `opaque` marks the points which block the effect.
+    ///
+    /// Opaque points bordering the area are reached but the effect does not pass them, points
+    /// outside the grid are never reached. The effect spreads from the origin, but the origin is
+    /// only reached when the shape contains it.
+    pub fn resolve(&self, opaque: &Grid<bool>) -> Vec<AreaOfEffectPoint> {
+        if opaque.get(self.origin).is_none() {
+            return Vec::new();
+        }
+
+        let positions: Vec<(i32, i32)> = match self.spread {
+            AreaOfEffectSpread::LineOfSight => std::iter::once(self.origin)
+                .filter(|&origin| self.shape.contains(origin))
+                .chain(self.shape.boxed_iter().filter(|&position| {
+                    position != self.origin
+                        && opaque.get(position).is_some()
+                        && line_of_sight_symmetric(opaque, self.origin, position, |&o| o)
+                }))
+                .collect(),
+            AreaOfEffectSpread::FloodFill => self.flood_fill(opaque),
+        };
+
+        positions
+            .into_iter()
+            .map(|position| {
+                let distance = self.distance.calculate(
+                    (self.origin.0 as f32, self.origin.1 as f32),
+                    (position.0 as f32, position.1 as f32),
+                );
+                AreaOfEffectPoint {
+                    position,
+                    distance,
+                    falloff: (1.0 - distance / self.falloff_range).clamp(0.0, 1.0),
+                }
+            })
+            .collect()
+    }
+
+    /// The points of the shape connected to the origin, in the order they are reached.
+    fn flood_fill(&self, opaque: &Grid<bool>) -> Vec<(i32, i32)> {
+        let mut reached = Vec::new();
+        if self.shape.contains(self.origin) {
+            reached.push(self.origin);
+        }
+        let mut visited = HashSet::from([self.origin]);
+        let mut queue = VecDeque::from([self.origin]);
+
+        while let Some(position) = queue.pop_front() {
+            if opaque[position] && position != self.origin {
+                continue;
+            }
+            for direction in Direction::iter_cardinal() {
+                let (x, y) = direction.coord();
+                let next = (position.0 + x, position.1 + y);
+                if opaque.get(next).is_none() || !self.shape.contains(next) || !visited.insert(next)
+                {
+                    continue;
+                }
+                reached.push(next);
+                queue.push_back(next);
+            }
+        }
+        reached
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{AreaOfEffect, AreaOfEffectSpread};
+    use crate::{
+        distance::Distance,
+        grid::Grid,
+        grid_shapes::{Circle, Rectangle},
+    };
+
+    /// A room with a wall along `x == 5` and a door at `(5, 1)`.
+    fn room() -> Grid<bool> {
+        let mut grid = Grid::new_copy((11, 11), false);
+        for y in 0..11 {
+            grid[(5i32, y)] = y != 1;
+        }
+        grid
+    }
+
+    #[test]
+    fn test_area_of_effect_walls() {
+        let grid = room();
+        let fireball = AreaOfEffect::new(Circle::new((3, 5), 4u32), (3, 5));
+
+        let reached: HashSet<(i32, i32)> = fireball
+            .resolve(&grid)
+            .into_iter()
+            .map(|point| point.position)
+            .collect();
+        assert!(reached.contains(&(3, 5)));
+        assert!(reached.contains(&(5, 5)));
+        assert!(!reached.iter().any(|position| position.0 > 5));
+
+        // floods through the door
+        let flood: HashSet<(i32, i32)> =
+            AreaOfEffect::new(Rectangle::new((0, 0), (10, 10)), (3, 5))
+                .with_spread(AreaOfEffectSpread::FloodFill)
+                .resolve(&grid)
+                .into_iter()
+                .map(|point| point.position)
+                .collect();
+        assert_eq!(flood.len(), 11 * 11);
+
+        let flood: HashSet<(i32, i32)> =
+            AreaOfEffect::new(Rectangle::new((0, 3), (10, 10)), (3, 5))
+                .with_spread(AreaOfEffectSpread::FloodFill)
+                .resolve(&grid)
+                .into_iter()
+                .map(|point| point.position)
+                .collect();
+        assert!(flood.contains(&(5, 7)));
+        assert!(!flood.contains(&(6, 7)));
+    }
+
+    #[test]
+    fn test_area_of_effect_outside_origin() {
+        let grid = room();
+        let shape = Rectangle::new((1, 3), (4, 7));
+        for spread in [
+            AreaOfEffectSpread::LineOfSight,
+            AreaOfEffectSpread::FloodFill,
+        ] {
+            let reached: HashSet<(i32, i32)> = AreaOfEffect::new(shape, (0, 5))
+                .with_spread(spread)
+                .resolve(&grid)
+                .into_iter()
+                .map(|point| point.position)
+                .collect();
+            assert!(!reached.contains(&(0, 5)), "{spread:?}");
+            assert_eq!(reached.len(), 4 * 5, "{spread:?}");
+        }
+
+        // a flood does not jump from the origin to a shape which is not next to it
+        let reached = AreaOfEffect::new(Rectangle::new((7, 3), (9, 7)), (3, 5))
+            .with_spread(AreaOfEffectSpread::FloodFill)
+            .resolve(&grid);
+        assert!(reached.is_empty());
+    }
+
+    #[test]
+    fn test_area_of_effect_falloff() {
+        let grid = room();
+        let points = AreaOfEffect::new(Rectangle::new((0, 0), (4, 10)), (0, 5))
+            .with_distance(Distance::Chebyshev)
+            .with_falloff_range(4.0)
+            .resolve(&grid);
+        assert_eq!(points.len(), 5 * 11);
+        for point in points {
+            let distance = (point.position.0).max((point.position.1 - 5).abs()) as f32;
+            assert_eq!(point.distance, distance);
+            assert_eq!(point.falloff, (1.0 - distance / 4.0).max(0.0));
+        }
+    }
+}
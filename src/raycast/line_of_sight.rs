@@ -0,0 +1,176 @@
+use crate::{
+    grid::Grid,
+    grid_shapes::{Line, Octant, ShapeIter, SymmetricLine},
+};
+
+/// Check if `b` can be seen from `a` along a [`Line`].
+///
+/// Only the points between `a` and `b` are checked, points outside the grid block.
+///
+/// NOTE: `Line` rounds away from `a`, so `b` may see `a` when `a` can not see `b`, see
+/// [`line_of_sight_symmetric`].
+pub fn line_of_sight<T>(
+    grid: &Grid<T>,
+    a: (i32, i32),
+    b: (i32, i32),
+    mut blocks: impl FnMut(&T) -> bool,
+) -> bool {
+    is_clear(grid, Line::new(a, b).iter(), a, b, &mut blocks)
+}
+
+/// Check if `b` can be seen from `a` along a [`SymmetricLine`].
+///
+/// Only the points between `a` and `b` are checked, points outside the grid block.
+pub fn line_of_sight_symmetric<T>(
+    grid: &Grid<T>,
+    a: (i32, i32),
+    b: (i32, i32),
+    mut blocks: impl FnMut(&T) -> bool,
+) -> bool {
+    is_clear(grid, SymmetricLine::new(a, b).iter(), a, b, &mut blocks)
+}
+
+/// Check if `b` can be seen from `a` along any line between them.
+///
+/// Tries every Bresenham error offset, which gives the same answer in both directions.
+/// Only the points between `a` and `b` are checked, points outside the grid block.
+pub fn line_of_sight_permissive<T>(
+    grid: &Grid<T>,
+    a: (i32, i32),
+    b: (i32, i32),
+    mut blocks: impl FnMut(&T) -> bool,
+) -> bool {
+    let steps = offset_count(a, b);
+    (0..steps).any(|offset| is_clear(grid, offset_line(a, b, offset), a, b, &mut blocks))
+}
+
+/// Get the share of lines between `a` and `b` which are not blocked.
+///
+/// `1.0` is fully visible and `0.0` is fully covered, the lines are the same as
+/// [`line_of_sight_permissive`] tries.
+pub fn line_of_sight_ratio<T>(
+    grid: &Grid<T>,
+    a: (i32, i32),
+    b: (i32, i32),
+    mut blocks: impl FnMut(&T) -> bool,
+) -> f32 {
+    let steps = offset_count(a, b);
+    let clear = (0..steps)
+        .filter(|&offset| is_clear(grid, offset_line(a, b, offset), a, b, &mut blocks))
+        .count();
+    clear as f32 / steps as f32
+}
+
+/// Check the points of `line` between `a` and `b`.
+fn is_clear<T>(
+    grid: &Grid<T>,
+    mut line: impl Iterator<Item = (i32, i32)>,
+    a: (i32, i32),
+    b: (i32, i32),
+    blocks: &mut impl FnMut(&T) -> bool,
+) -> bool {
+    line.all(|position| {
+        position == a || position == b || grid.get(position).is_some_and(|value| !blocks(value))
+    })
+}
+
+/// The number of distinct error offsets of lines between `a` and `b`, at least `1`.
+fn offset_count(a: (i32, i32), b: (i32, i32)) -> i32 {
+    (b.0 - a.0).abs().max((b.1 - a.1).abs()).max(1)
+}
+
+/// The line between `a` and `b` which starts with an error of `offset`.
+///
+/// Every `offset` in `0..offset_count(a, b)` connects `a` and `b`.
+fn offset_line(a: (i32, i32), b: (i32, i32), offset: i32) -> impl Iterator<Item = (i32, i32)> {
+    let octant = Octant::new(a, b);
+    let (delta_x, delta_y) = octant.to_offset((b.0 - a.0, b.1 - a.1));
+    (0..=delta_x).map(move |step| {
+        let y = if delta_x == 0 {
+            0
+        } else {
+            (step * delta_y + offset).div_euclid(delta_x)
+        };
+        let (x, y) = octant.from_offset((step, y));
+        (a.0 + x, a.1 + y)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        line_of_sight, line_of_sight_permissive, line_of_sight_ratio, line_of_sight_symmetric,
+    };
+    use crate::{grid::Grid, grid_shapes::Rectangle};
+
+    fn pillars() -> Grid<bool> {
+        let mut grid = Grid::new_copy((12, 12), false);
+        for position in [(3, 3), (4, 7), (6, 5), (8, 2), (9, 9), (2, 9)] {
+            grid[position] = true;
+        }
+        grid
+    }
+
+    #[test]
+    fn test_line_of_sight_symmetric() {
+        let grid = pillars();
+        for a in Rectangle::new((0, 0), (11, 11)) {
+            for b in [(0, 0), (11, 5), (5, 11), (7, 7), (10, 1)] {
+                assert_eq!(
+                    line_of_sight_symmetric(&grid, a, b, |&wall| wall),
+                    line_of_sight_symmetric(&grid, b, a, |&wall| wall),
+                    "{a:?} {b:?}"
+                );
+
+                let permissive = line_of_sight_permissive(&grid, a, b, |&wall| wall);
+                assert_eq!(
+                    permissive,
+                    line_of_sight_permissive(&grid, b, a, |&wall| wall)
+                );
+                assert_eq!(
+                    line_of_sight_ratio(&grid, a, b, |&wall| wall),
+                    line_of_sight_ratio(&grid, b, a, |&wall| wall)
+                );
+                if line_of_sight(&grid, a, b, |&wall| wall) {
+                    assert!(permissive, "{a:?} {b:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_line_of_sight_ratio() {
+        let mut grid = Grid::new_copy((12, 12), false);
+        assert_eq!(
+            line_of_sight_ratio(&grid, (0, 0), (8, 3), |&wall| wall),
+            1.0
+        );
+        assert_eq!(
+            line_of_sight_ratio(&grid, (2, 2), (2, 2), |&wall| wall),
+            1.0
+        );
+
+        // a wall is always visible, but not through
+        grid[(4i32, 0)] = true;
+        assert!(line_of_sight(&grid, (0, 0), (4, 0), |&wall| wall));
+        assert!(!line_of_sight(&grid, (0, 0), (8, 0), |&wall| wall));
+
+        // partial cover
+        grid[(4i32, 1)] = true;
+        let ratio = line_of_sight_ratio(&grid, (0, 0), (8, 3), |&wall| wall);
+        assert!(ratio > 0.0 && ratio < 1.0, "{ratio}");
+        assert!(line_of_sight_permissive(&grid, (0, 0), (8, 3), |&wall| {
+            wall
+        }));
+
+        // full cover
+        grid[(4i32, 2)] = true;
+        assert_eq!(
+            line_of_sight_ratio(&grid, (0, 0), (8, 3), |&wall| wall),
+            0.0
+        );
+        assert!(!line_of_sight_permissive(&grid, (0, 0), (8, 3), |&wall| {
+            wall
+        }));
+    }
+}
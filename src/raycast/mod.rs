@@ -0,0 +1,6 @@
+mod raycast;
+pub use self::raycast::*;
+mod line_of_sight;
+pub use self::line_of_sight::*;
+mod area_of_effect;
+pub use self::area_of_effect::*;
@@ -0,0 +1,220 @@
+use crate::{direction::Direction, grid::Grid, grid_shapes::iter::BresenhamLineIter};
+
+/// A ray walking a line from `start` until it reaches its range or a blocking point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Raycast {
+    start: (i32, i32),
+    target: (i32, i32),
+    range: u32,
+    pass_target: bool,
+}
+
+/// The blocking point a [`Raycast`] stopped at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaycastHit {
+    /// The blocking point.
+    pub position: (i32, i32),
+    /// The face of the blocking point the ray entered through, facing back towards the ray.
+    ///
+    /// A diagonal face means the ray hit a corner head on.
+    pub face: Direction,
+}
+
+/// The result of a [`Raycast`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RaycastResult {
+    /// The points the ray passed through, not including `start` or the blocking point.
+    pub path: Vec<(i32, i32)>,
+    /// The blocking point, `None` if the ray ran out of range.
+    pub hit: Option<RaycastHit>,
+}
+
+impl Raycast {
+    /// Creates a new ray from `start` which stops at `target`.
+    #[inline]
+    pub fn new(start: (i32, i32), target: (i32, i32)) -> Self {
+        let range = (target.0 - start.0)
+            .unsigned_abs()
+            .max((target.1 - start.1).unsigned_abs());
+        Self {
+            start,
+            target,
+            range,
+            pass_target: false,
+        }
+    }
+
+    /// Creates a new ray from `start` travelling `range` points towards `direction`.
+    #[inline]
+    pub fn from_direction(start: (i32, i32), direction: Direction, range: u32) -> Self {
+        let (x, y) = direction.coord();
+        let target = (start.0 + x * range as i32, start.1 + y * range as i32);
+        Self::new(start, target)
+    }
+
+    /// Sets the number of points the ray may travel.
+    #[inline]
+    pub const fn with_range(mut self, range: u32) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets if the ray keeps travelling along the line past `target` until it runs out of range.
+    #[inline]
+    pub const fn with_pass_target(mut self, pass_target: bool) -> Self {
+        self.pass_target = pass_target;
+        self
+    }
+}
+
+impl Raycast {
+    /// Get the point the ray starts from.
+    #[inline]
+    pub const fn start(&self) -> (i32, i32) {
+        self.start
+    }
+
+    /// Get the point the ray is aimed at.
+    #[inline]
+    pub const fn target(&self) -> (i32, i32) {
+        self.target
+    }
+
+    /// Get the number of points the ray may travel.
+    #[inline]
+    pub const fn range(&self) -> u32 {
+        self.range
+    }
+
+    /// Casts the ray, stopping at the first point where `is_blocked` returns `true`.
+    ///
+    /// `start` is never checked.
+    pub fn cast(&self, mut is_blocked: impl FnMut((i32, i32)) -> bool) -> RaycastResult {
+        self.walk(|position| Some(is_blocked(position)))
+    }
+
+    /// Casts the ray across a grid, stopping at the first point where `is_blocked` returns
+    /// `true`.
+    ///
+    /// The ray stops without a hit when it leaves the grid, `start` is never checked.
+    pub fn cast_grid<T>(
+        &self,
+        grid: &Grid<T>,
+        mut is_blocked: impl FnMut(&T) -> bool,
+    ) -> RaycastResult {
+        self.walk(|position| grid.get(position).map(&mut is_blocked))
+    }
+
+    /// Walks the ray, `is_blocked` returns `None` to stop without a hit.
+    fn walk(&self, mut is_blocked: impl FnMut((i32, i32)) -> Option<bool>) -> RaycastResult {
+        let mut result = RaycastResult::default();
+        if self.start == self.target {
+            return result;
+        }
+
+        let mut line = BresenhamLineIter::new(self.start, self.target);
+        let mut previous = line.advance();
+        for _ in 0..self.range {
+            if previous == self.target && !self.pass_target {
+                break;
+            }
+
+            let position = line.advance();
+            match is_blocked(position) {
+                Some(false) => {}
+                Some(true) => {
+                    result.hit = Some(RaycastHit {
+                        position,
+                        face: hit_face(previous, position, &mut is_blocked),
+                    });
+                    break;
+                }
+                None => break,
+            }
+            result.path.push(position);
+            previous = position;
+        }
+        result
+    }
+}
+
+/// The face of `hit` entered when stepping from `previous`.
+///
+/// A diagonal step between a wall and an open point hits the side of the wall.
+fn hit_face(
+    previous: (i32, i32),
+    hit: (i32, i32),
+    is_blocked: &mut impl FnMut((i32, i32)) -> Option<bool>,
+) -> Direction {
+    let step = (hit.0 - previous.0, hit.1 - previous.1);
+    if step.0 != 0 && step.1 != 0 {
+        let beside_x = is_blocked((hit.0, previous.1)) == Some(true);
+        let beside_y = is_blocked((previous.0, hit.1)) == Some(true);
+        if beside_x && !beside_y {
+            // walls run along `Y`
+            return Direction::from_coord((-step.0, 0));
+        }
+        if beside_y && !beside_x {
+            // walls run along `X`
+            return Direction::from_coord((0, -step.1));
+        }
+    }
+    Direction::from_coord((-step.0, -step.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Raycast, RaycastHit};
+    use crate::{direction::Direction, grid::Grid};
+
+    #[test]
+    fn test_raycast_range() {
+        let ray = Raycast::new((0, 0), (4, 2));
+        let result = ray.cast(|_| false);
+        assert_eq!(result.hit, None);
+        assert_eq!(result.path.len(), 4);
+        assert_eq!(result.path.last(), Some(&(4, 2)));
+
+        let result = ray.with_pass_target(true).with_range(8).cast(|_| false);
+        assert_eq!(result.path.len(), 8);
+        assert_eq!(result.path.last(), Some(&(8, 4)));
+
+        let result = Raycast::from_direction((1, 1), Direction::SOUTH_WEST, 3).cast(|_| false);
+        assert_eq!(result.path, [(0, 0), (-1, -1), (-2, -2)]);
+    }
+
+    #[test]
+    fn test_raycast_hit() {
+        // a wall along `x == 5`
+        let mut grid = Grid::new_copy((10, 10), false);
+        for y in 0..10 {
+            grid[(5i32, y)] = true;
+        }
+
+        let ray = Raycast::from_direction((1, 4), Direction::EAST, 8);
+        let result = ray.cast_grid(&grid, |&wall| wall);
+        assert_eq!(result.path, [(2, 4), (3, 4), (4, 4)]);
+        assert_eq!(
+            result.hit,
+            Some(RaycastHit {
+                position: (5, 4),
+                face: Direction::WEST,
+            })
+        );
+
+        // a diagonal ray hits the side of the wall
+        let ray = Raycast::from_direction((2, 2), Direction::NORTH_EAST, 8);
+        let hit = ray.cast_grid(&grid, |&wall| wall).hit.unwrap();
+        assert_eq!(hit.position, (5, 5));
+        assert_eq!(hit.face, Direction::WEST);
+
+        // the ray leaves the grid
+        let ray = Raycast::from_direction((1, 4), Direction::WEST, 8);
+        let result = ray.cast_grid(&grid, |&wall| wall);
+        assert_eq!((result.path.as_slice(), result.hit), (&[(0, 4)][..], None));
+
+        // a lone pillar is hit on the corner
+        let result = Raycast::new((0, 0), (3, 3)).cast(|position| position == (2, 2));
+        assert_eq!(result.hit.unwrap().face, Direction::SOUTH_WEST);
+    }
+}